@@ -0,0 +1,103 @@
+//! Helpers for reading the logical values of canonical arrays.
+
+use std::fmt;
+
+use vortex::{
+    array::BoolArray,
+    compute::try_cast,
+    dtype::{DType, NativePType, Nullability, PType},
+    error::{vortex_bail, VortexResult},
+    validity::LogicalValidity,
+    ArrayDType, ArrayData, IntoArrayVariant,
+};
+
+/// A primitive value that can be hashed and compared for equality.
+///
+/// Integers keep their full range instead of being widened to `f64`. Floats are compared by value,
+/// so `0.0` and `-0.0` are the same value, as are all NaNs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrimitiveKey {
+    Int(i128),
+    Float(u64),
+}
+
+impl PrimitiveKey {
+    fn float(value: f64) -> Self {
+        let value = if value == 0.0 {
+            0.0
+        } else if value.is_nan() {
+            f64::NAN
+        } else {
+            value
+        };
+        Self::Float(value.to_bits())
+    }
+
+    /// The value as a float, for computing moments. Large integers lose precision.
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Self::Int(value) => value as f64,
+            Self::Float(bits) => f64::from_bits(bits),
+        }
+    }
+}
+
+impl fmt::Display for PrimitiveKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(bits) => write!(f, "{}", f64::from_bits(*bits)),
+        }
+    }
+}
+
+/// Call `f` with the index and value of every element of a primitive array, nulls included.
+pub fn for_each_primitive(
+    array: &ArrayData,
+    mut f: impl FnMut(usize, PrimitiveKey),
+) -> VortexResult<()> {
+    fn ints<T: NativePType + Into<i128>>(
+        array: &ArrayData,
+        f: &mut impl FnMut(usize, PrimitiveKey),
+    ) -> VortexResult<()> {
+        let values = array.clone().into_primitive()?;
+        for (idx, value) in values.maybe_null_slice::<T>().iter().enumerate() {
+            f(idx, PrimitiveKey::Int((*value).into()));
+        }
+        Ok(())
+    }
+
+    let DType::Primitive(ptype, _) = array.dtype() else {
+        vortex_bail!("Expected a primitive array, found {}", array.dtype());
+    };
+    match ptype {
+        PType::U8 => ints::<u8>(array, &mut f),
+        PType::U16 => ints::<u16>(array, &mut f),
+        PType::U32 => ints::<u32>(array, &mut f),
+        PType::U64 => ints::<u64>(array, &mut f),
+        PType::I8 => ints::<i8>(array, &mut f),
+        PType::I16 => ints::<i16>(array, &mut f),
+        PType::I32 => ints::<i32>(array, &mut f),
+        PType::I64 => ints::<i64>(array, &mut f),
+        // Every float type widens to f64 exactly.
+        PType::F16 | PType::F32 | PType::F64 => {
+            let values = try_cast(array, &DType::Primitive(PType::F64, Nullability::Nullable))?
+                .into_primitive()?;
+            for (idx, value) in values.maybe_null_slice::<f64>().iter().enumerate() {
+                f(idx, PrimitiveKey::float(*value));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Materialize the validity of an array. Returns `None` when every value is valid.
+pub fn validity_mask(validity: LogicalValidity) -> VortexResult<Option<BoolArray>> {
+    Ok(match validity {
+        LogicalValidity::AllValid(_) => None,
+        LogicalValidity::AllInvalid(len) => {
+            Some(BoolArray::from_iter(std::iter::repeat(false).take(len)))
+        }
+        LogicalValidity::Array(array) => Some(array.into_bool()?),
+    })
+}
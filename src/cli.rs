@@ -0,0 +1,465 @@
+//! Command line interface, for looking at files without starting the app.
+//!
+//! `vortex-app inspect file.vortex [--path a/b/c] [--json]` prints the same summary, encoding tree,
+//! statistics, encoding parameters and schema as the explore view shows for the node at `path`.
+//! `vortex-app report file.vortex [--html] [--output report.json]` writes the JSON [`Report`] of the
+//! whole tree, or the standalone HTML report of the file, the same as the downloads of the app.
+
+use std::ffi::{OsStr, OsString};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use bytes::Bytes;
+use serde::Serialize;
+use vortex::{ArrayDType, ArrayData};
+
+use crate::components::array_info::EncodingRegistry;
+use crate::file_util::{open_file, ReaderConfig};
+use crate::find_child;
+use crate::html_report::html_report;
+use crate::report::Report;
+use crate::summary::{
+    encoding_label, encoding_tree, node_children, node_nbytes, NodeSummary, SchemaNode,
+};
+
+const USAGE: &str = "\
+Usage:
+  vortex-app [FILE]...
+  vortex-app inspect <FILE> [--path <PATH>] [--json]
+  vortex-app report <FILE> [--html] [--output <OUT>]
+
+Without a command, the desktop app opens with each FILE in its own tab.
+
+Commands:
+  inspect  Print the summary, encoding tree, statistics, encoding parameters and schema of a node
+  report   Print a JSON report of every node of the file, or a standalone HTML report
+
+Options:
+  --path <PATH>   Inspect the node at PATH, child names separated by '/', e.g. 'a/codes'
+  --json          Print the result of inspect as JSON
+  --html          Write the standalone HTML report instead of the JSON one
+  --output <OUT>  Write the report to OUT instead of standard output";
+
+/// Run the command line interface.
+///
+/// Returns the exit code if the arguments are a command, or `None` if they are files to open and
+/// the app should be launched instead. Arguments are taken as given by the OS, so file names do not
+/// have to be valid UTF-8.
+pub fn run(args: impl IntoIterator<Item = OsString>) -> Option<i32> {
+    let mut args = args.into_iter().peekable();
+    let command = match args.peek().and_then(|arg| arg.to_str()) {
+        Some(command @ ("inspect" | "report")) => command.to_string(),
+        _ => return check_files(args),
+    };
+    args.next();
+
+    if args
+        .peek()
+        .is_some_and(|arg| arg == "-h" || arg == "--help")
+    {
+        println!("{USAGE}");
+        return Some(0);
+    }
+
+    let args = match Args::parse(&command, args) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            return Some(2);
+        }
+    };
+
+    let code = if command == "inspect" {
+        finish(inspect(&args), args.json)
+    } else {
+        finish(write_report(&args), false)
+    };
+    Some(code)
+}
+
+/// Check the arguments the app is launched with are files, rather than options it does not take.
+///
+/// Returns the exit code if they are not.
+fn check_files(mut args: impl Iterator<Item = OsString>) -> Option<i32> {
+    match args.find(|arg| is_option(arg))? {
+        arg if arg == "-h" || arg == "--help" => {
+            println!("{USAGE}");
+            Some(0)
+        }
+        arg => {
+            eprintln!("error: unknown option {}\n\n{USAGE}", arg.to_string_lossy());
+            Some(2)
+        }
+    }
+}
+
+fn is_option(arg: &OsStr) -> bool {
+    arg.as_encoded_bytes().starts_with(b"-")
+}
+
+/// Print the outcome of a command, returning the exit code.
+fn finish(result: Result<String, String>, json: bool) -> i32 {
+    match result {
+        Ok(output) if output.is_empty() => 0,
+        Ok(output) => {
+            println!("{output}");
+            0
+        }
+        Err(message) if json => {
+            let error = serde_json::json!({ "error": message });
+            println!("{error}");
+            1
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            1
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Args {
+    file: PathBuf,
+    path: Vec<String>,
+    json: bool,
+    html: bool,
+    output: Option<PathBuf>,
+}
+
+impl Args {
+    fn parse(command: &str, args: impl Iterator<Item = OsString>) -> Result<Self, String> {
+        let mut file = None;
+        let mut path = Vec::new();
+        let mut json = false;
+        let mut html = false;
+        let mut output = None;
+
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            // Options are all ASCII, anything else can only be a file.
+            let Some(flag) = arg.to_str() else {
+                if is_option(&arg) {
+                    return Err(format!(
+                        "unknown option {} for {command}",
+                        arg.to_string_lossy()
+                    ));
+                }
+                if file.is_some() {
+                    return Err(format!("unexpected argument {}", arg.to_string_lossy()));
+                }
+                file = Some(PathBuf::from(arg));
+                continue;
+            };
+            match (command, flag) {
+                ("inspect", "--json") => json = true,
+                ("inspect", "--path") => {
+                    let value = args.next().ok_or("--path needs a value")?;
+                    let value = value.to_str().ok_or("--path must be valid UTF-8")?;
+                    path = parse_path(value);
+                }
+                ("inspect", flag) if flag.starts_with("--path=") => {
+                    path = parse_path(&flag["--path=".len()..])
+                }
+                ("report", "--html") => html = true,
+                ("report", "--output") => {
+                    output = Some(PathBuf::from(args.next().ok_or("--output needs a value")?));
+                }
+                (_, flag) if flag.starts_with('-') => {
+                    return Err(format!("unknown option {flag} for {command}"))
+                }
+                _ if file.is_some() => return Err(format!("unexpected argument {flag}")),
+                _ => file = Some(PathBuf::from(flag)),
+            }
+        }
+
+        Ok(Self {
+            file: file.ok_or("no file given")?,
+            path,
+            json,
+            html,
+            output,
+        })
+    }
+}
+
+/// Read and decode a whole file.
+fn open(file: &Path) -> Result<ArrayData, String> {
+    let contents =
+        std::fs::read(file).map_err(|err| format!("could not read {}: {err}", file.display()))?;
+    futures::executor::block_on(open_file(Bytes::from(contents), &ReaderConfig::default())).map_err(
+        |err| {
+            // The chain usually says which part of the file was at fault.
+            let mut message = err.to_string();
+            for cause in &err.chain {
+                let _ = write!(message, "\n  caused by: {cause}");
+            }
+            message
+        },
+    )
+}
+
+fn write_report(args: &Args) -> Result<String, String> {
+    let root = open(&args.file)?;
+    let file_name = args
+        .file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| args.file.display().to_string());
+    let registry = EncodingRegistry::builtin();
+    let report = if args.html {
+        html_report(&file_name, &root, &registry)
+    } else {
+        Report::try_new(&file_name, &root, &registry).and_then(|report| report.to_json())
+    }
+    .map_err(|err| err.to_string())?;
+
+    match &args.output {
+        Some(output) => {
+            std::fs::write(output, report)
+                .map_err(|err| format!("could not write {}: {err}", output.display()))?;
+            Ok(String::new())
+        }
+        None => Ok(report),
+    }
+}
+
+fn parse_path(value: &str) -> Vec<String> {
+    value
+        .split('/')
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Everything shown about one node of a file.
+#[derive(Debug, Serialize)]
+struct Inspection {
+    file: String,
+    path: Vec<String>,
+    #[serde(flatten)]
+    summary: NodeSummary,
+    /// Compact description of the encodings of the node and all of its descendants.
+    encoding_tree: String,
+    schema: SchemaNode,
+    children: Vec<ChildSummary>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChildSummary {
+    name: String,
+    encoding: String,
+    len: usize,
+    nbytes: usize,
+}
+
+fn inspect(args: &Args) -> Result<String, String> {
+    let root = open(&args.file)?;
+
+    let mut array = root;
+    for (depth, name) in args.path.iter().enumerate() {
+        array = find_child(&array, name).ok_or_else(|| {
+            format!(
+                "no child named {name} under /{}",
+                args.path[..depth].join("/")
+            )
+        })?;
+    }
+
+    let inspection = Inspection {
+        file: args.file.display().to_string(),
+        path: args.path.clone(),
+        summary: NodeSummary::try_new(&array, &EncodingRegistry::builtin())
+            .map_err(|err| err.to_string())?,
+        encoding_tree: encoding_tree(&array),
+        schema: SchemaNode::new(
+            args.path
+                .last()
+                .cloned()
+                .unwrap_or_else(|| "root".to_string()),
+            array.dtype(),
+        ),
+        children: node_children(&array)
+            .into_iter()
+            .map(|(name, child)| ChildSummary {
+                name,
+                encoding: encoding_label(&child),
+                len: child.len(),
+                nbytes: node_nbytes(&child),
+            })
+            .collect(),
+    };
+
+    if args.json {
+        serde_json::to_string_pretty(&inspection).map_err(|err| err.to_string())
+    } else {
+        Ok(format_inspection(&inspection, &array))
+    }
+}
+
+fn format_inspection(inspection: &Inspection, array: &ArrayData) -> String {
+    let summary = &inspection.summary;
+    let mut out = String::new();
+
+    let _ = writeln!(out, "File:      {}", inspection.file);
+    let _ = writeln!(out, "Path:      /{}", inspection.path.join("/"));
+    let _ = writeln!(out, "Encoding:  {}", summary.encoding);
+    let _ = writeln!(out, "DType:     {}", summary.dtype);
+    let _ = writeln!(out, "Rows:      {}", summary.len);
+    let _ = writeln!(
+        out,
+        "Size:      {}",
+        humansize::format_size(summary.nbytes, humansize::BINARY)
+    );
+    let null_pct = 100. * (summary.null_count as f64) / (summary.len.max(1) as f64);
+    let _ = writeln!(out, "Nulls:     {} ({null_pct:.2}%)", summary.null_count);
+
+    if !summary.parameters.is_empty() {
+        let _ = writeln!(out, "\nEncoding parameters");
+        for (label, value) in &summary.parameters {
+            let _ = writeln!(out, "  {label}: {value}");
+        }
+    }
+
+    let _ = writeln!(out, "\nEncoding tree");
+    write_encoding_tree(&mut out, "root", array, 1);
+
+    if !summary.stats.is_empty() {
+        let _ = writeln!(out, "\nStatistics");
+        for (stat, value) in &summary.stats {
+            let _ = writeln!(out, "  {stat}: {value}");
+        }
+    }
+
+    let _ = writeln!(out, "\nSchema");
+    write_schema(&mut out, &inspection.schema, 1);
+
+    if !inspection.children.is_empty() {
+        let _ = writeln!(out, "\nChildren");
+        for child in &inspection.children {
+            let _ = writeln!(
+                out,
+                "  {}: {}, {} rows, {}",
+                child.name,
+                child.encoding,
+                child.len,
+                humansize::format_size(child.nbytes, humansize::BINARY)
+            );
+        }
+    }
+
+    out.trim_end().to_string()
+}
+
+/// Write one line per node of the array tree, indented by depth.
+fn write_encoding_tree(out: &mut String, name: &str, array: &ArrayData, depth: usize) {
+    let _ = writeln!(
+        out,
+        "{:indent$}{name}: {} ({})",
+        "",
+        encoding_label(array),
+        humansize::format_size(node_nbytes(array), humansize::BINARY),
+        indent = depth * 2
+    );
+    for (child_name, child) in node_children(array) {
+        write_encoding_tree(out, &child_name, &child, depth + 1);
+    }
+}
+
+fn write_schema(out: &mut String, node: &SchemaNode, depth: usize) {
+    let nullable = if node.nullable { "?" } else { "" };
+    let _ = writeln!(
+        out,
+        "{:indent$}{}: {}{nullable}",
+        "",
+        node.name,
+        node.dtype,
+        indent = depth * 2
+    );
+    for field in &node.fields {
+        write_schema(out, field, depth + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    fn parse(command: &str, values: &[&str]) -> Result<Args, String> {
+        Args::parse(command, args(values).into_iter())
+    }
+
+    #[test]
+    fn inspect_args() {
+        let parsed = parse("inspect", &["file.vortex"]).unwrap();
+        assert_eq!(parsed.file, PathBuf::from("file.vortex"));
+        assert!(parsed.path.is_empty());
+        assert!(!parsed.json);
+
+        let parsed = parse(
+            "inspect",
+            &["--json", "file.vortex", "--path", "/a//codes/"],
+        )
+        .unwrap();
+        assert_eq!(parsed.path, ["a", "codes"]);
+        assert!(parsed.json);
+
+        let parsed = parse("inspect", &["file.vortex", "--path=a/b"]).unwrap();
+        assert_eq!(parsed.path, ["a", "b"]);
+    }
+
+    #[test]
+    fn report_args() {
+        let parsed = parse("report", &["file.vortex", "--html", "--output", "out.html"]).unwrap();
+        assert_eq!(parsed.file, PathBuf::from("file.vortex"));
+        assert!(parsed.html);
+        assert_eq!(parsed.output, Some(PathBuf::from("out.html")));
+    }
+
+    #[test]
+    fn rejected_args() {
+        assert!(parse("inspect", &[]).is_err());
+        assert!(parse("inspect", &["file.vortex", "--path"]).is_err());
+        assert!(parse("inspect", &["file.vortex", "other.vortex"]).is_err());
+        assert!(parse("inspect", &["file.vortex", "--verbose"]).is_err());
+        assert!(parse("report", &["file.vortex", "--output"]).is_err());
+        // Options only belong to their own command.
+        assert!(parse("inspect", &["file.vortex", "--html"]).is_err());
+        assert!(parse("report", &["file.vortex", "--json"]).is_err());
+        assert!(parse("report", &["file.vortex", "--path", "a"]).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_args() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let file = OsString::from_vec(b"caf\xe9.vortex".to_vec());
+        let parsed = Args::parse("inspect", vec![file.clone()].into_iter()).unwrap();
+        assert_eq!(parsed.file, PathBuf::from(&file));
+
+        let path = OsString::from_vec(b"caf\xe9".to_vec());
+        let values = vec!["file.vortex".into(), "--path".into(), path];
+        assert!(Args::parse("inspect", values.into_iter()).is_err());
+
+        // Files to open in the app are passed through as they are.
+        assert_eq!(run(vec![file]), None);
+    }
+
+    #[test]
+    fn exit_codes() {
+        assert_eq!(run(args(&[])), None);
+        assert_eq!(run(args(&["a.vortex", "b.vortex"])), None);
+        assert_eq!(run(args(&["--help"])), Some(0));
+        assert_eq!(run(args(&["a.vortex", "-h"])), Some(0));
+        assert_eq!(run(args(&["a.vortex", "--verbose"])), Some(2));
+        assert_eq!(run(args(&["inspect", "--help"])), Some(0));
+        assert_eq!(run(args(&["inspect"])), Some(2));
+        assert_eq!(run(args(&["report", "a.vortex", "--json"])), Some(2));
+        assert_eq!(run(args(&["inspect", "missing.vortex"])), Some(1));
+        assert_eq!(run(args(&["inspect", "missing.vortex", "--json"])), Some(1));
+    }
+}
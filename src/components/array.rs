@@ -1,52 +1,125 @@
 use crate::components::breadcrumb::Breadcrumbs;
 use crate::{
-    components::{array_info::EncodingInfo, dtype::DTypeInfo, stats::Statistics, Heading},
+    components::{
+        array_info::{EncodingInfo, EncodingRegistry},
+        buffers::BuffersPanel,
+        dtype::DTypeInfo,
+        graph::TreeGraph,
+        metadata::MetadataPanel,
+        stats::Statistics,
+        validity::ValidityInfo,
+        yield_to_ui, Heading,
+    },
+    sketch::HyperLogLog,
+    summary::{encoding_label, encoding_parameters, node_children, node_nbytes, unknown_encoding},
     HistoryItem, HistoryStack, SharedPtr,
 };
 use dioxus::{logger::tracing, prelude::*};
-use vortex::{stats::ArrayStatistics, validity::ArrayValidity, ArrayDType, ArrayData};
+use vortex::{
+    compute::slice,
+    dict::{DictArray, DictEncoding},
+    encoding::Encoding,
+    error::VortexResult,
+    stats::ArrayStatistics,
+    validity::ArrayValidity,
+    ArrayDType, ArrayData,
+};
 
 /// Show some basic info about an ArrayView.
 #[component]
 pub fn ArrayView(file_name: String, history_stack: Signal<HistoryStack>) -> Element {
     // Use the history stack to take data from the front/back of the stack
-    let HistoryItem { array, .. } = history_stack().current().unwrap().clone();
+    let HistoryItem { name, array } = history_stack().current().unwrap().clone();
     let stats = array.statistics().to_set();
+    // Arrays of unknown encodings cannot be decoded, only their serialized form can be shown.
+    let unknown = unknown_encoding(&array);
 
     rsx! {
-        div { class: "flex flex-col mt-4",
+        div {
+            class: "flex flex-col mt-4 outline-none",
+            tabindex: 0,
+            onkeydown: move |evt| navigate_with_keyboard(evt, history_stack),
+
             Breadcrumbs { history_stack }
 
-            // schema, row_count
-            ArraySummary { array: array.clone(), file_name: file_name.clone() }
+            if let Some(code) = unknown {
+                Heading { text: "Unknown Encoding {code}" }
+                p { class: "font-sans text-sm",
+                    "This node uses an encoding the reader does not know, so it cannot be decoded. "
+                    "Its {array.len()} rows, metadata and buffers are shown as stored in the file."
+                }
+
+                MetadataPanel { array: array.clone() }
+
+                div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
+
+                BuffersPanel { array: array.clone(), contents: history_stack().contents().cloned() }
+
+                div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
+            } else {
+                // schema, row_count
+                ArraySummary { array: array.clone(), file_name: file_name.clone() }
+
+                EncodingInfo { array: array.clone() }
+
+                MetadataPanel { array: array.clone() }
+
+                div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
+
+                ValidityInfo { array: array.clone() }
+
+                div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
 
-            EncodingInfo { array: array.clone() }
+                BuffersPanel { array: array.clone(), contents: history_stack().contents().cloned() }
 
-            div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
+                div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
 
-            // Stats.
-            Statistics { stats }
+                // Stats.
+                Statistics { stats, dtype: array.dtype().clone() }
 
-            div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
+                div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
 
-            DTypeInfo { dtype: array.dtype().clone() }
+                DTypeInfo { array: array.clone(), history_stack }
 
-            div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
+                div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
 
-            if !array.children().is_empty() {
-                ArrayChildren { history_stack }
+                if !node_children(&array).is_empty() {
+                    ArrayChildren { history_stack }
+
+                    div { class: "my-12 h-0.5 border-t-0 bg-neutral-100/30" }
+
+                    TreeGraph { name, array: array.clone(), history_stack }
+                }
             }
         }
     }
 }
 
+/// Alt+Left goes back, Alt+Right forward and Alt+Up to the parent node.
+fn navigate_with_keyboard(evt: KeyboardEvent, mut history_stack: Signal<HistoryStack>) {
+    if !evt.modifiers().contains(Modifiers::ALT) {
+        return;
+    }
+
+    match evt.key() {
+        Key::ArrowLeft => history_stack.write().back(),
+        Key::ArrowRight => history_stack.write().forward(),
+        Key::ArrowUp => history_stack.write().up(),
+        _ => return,
+    }
+    evt.prevent_default();
+}
+
 #[component]
 fn ArraySummary(array: SharedPtr<ArrayData>, file_name: String) -> Element {
+    let registry = use_context::<EncodingRegistry>();
     let size = humansize::format_size(array.nbytes(), humansize::BINARY);
     let row_count = array.len();
-    let encoding_id = array.encoding().id().to_string();
+    let encoding_id = encoding_label(&array);
     let null_count = array.logical_validity().null_count()?;
     let null_pct: f64 = 100. * (null_count as f64) / (row_count as f64);
+    // Headline metrics of the encoding, if its panel provides any.
+    let metrics = encoding_parameters(&array, &registry)?;
 
     rsx! {
         div {
@@ -115,6 +188,30 @@ fn ArraySummary(array: SharedPtr<ArrayData>, file_name: String) -> Element {
                                 }
                             }
                         }
+                        tr { class: "font-normal hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
+                            td { class: "p-1",
+                                p { class: "block font-sans font-bold text-sm antialiased leading-normal",
+                                    "Distinct Values"
+                                }
+                            }
+                            td { class: "p-1",
+                                Cardinality { array: array.clone() }
+                            }
+                        }
+                        for (label , value) in metrics {
+                            tr { class: "font-normal hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
+                                td { class: "p-1",
+                                    p { class: "block font-sans font-bold text-sm antialiased leading-normal",
+                                        "{label}"
+                                    }
+                                }
+                                td { class: "p-1",
+                                    p { class: "block font-mono text-sm antialiased leading-normal",
+                                        "{value}"
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -135,7 +232,7 @@ pub fn ArrayChildren(mut history_stack: Signal<HistoryStack>) -> Element {
 
         table { class: "table-auto w-full min-w-max max-h-96 overflow-y-scroll text-left border-collapse",
             tbody { class: "border-b border-1 border-zinc-50/10",
-                for (idx , (name , child)) in array.named_children().into_iter().enumerate() {
+                for (idx , (name , child)) in node_children(&array).into_iter().enumerate() {
                     tr {
                         class: "font-normal border-b border-1 border-zinc-50/10",
                         // Interactivity
@@ -159,7 +256,7 @@ pub fn ArrayChildren(mut history_stack: Signal<HistoryStack>) -> Element {
                         }
                         td { class: "p-2",
                             p { class: "block font-mono text-sm antialiased leading-normal",
-                                "{humansize::format_size(child.nbytes(), humansize::BINARY)}"
+                                "{humansize::format_size(node_nbytes(&child), humansize::BINARY)}"
                             }
                         }
                     }
@@ -168,3 +265,84 @@ pub fn ArrayChildren(mut history_stack: Signal<HistoryStack>) -> Element {
         }
     }
 }
+
+/// On-demand approximate distinct count for an array.
+///
+/// For dictionary-encoded arrays the size of the dictionary is shown alongside for comparison.
+#[component]
+fn Cardinality(array: SharedPtr<ArrayData>) -> Element {
+    // Tag the estimate with the array it was computed for, so it is not shown for other nodes. The
+    // result is `None` while the estimate is still running.
+    let mut estimate =
+        use_signal::<Option<(SharedPtr<ArrayData>, Option<Result<usize, String>>)>>(|| None);
+
+    let dict_size = if array.encoding().id() == DictEncoding::ID {
+        Some(DictArray::try_from((*array).clone())?.values().len())
+    } else {
+        None
+    };
+
+    let current = estimate
+        .read()
+        .as_ref()
+        .filter(|(estimated, _)| *estimated == array)
+        .map(|(_, result)| result.clone());
+
+    rsx! {
+        div { class: "flex flex-row items-center gap-x-2",
+            {
+                match current {
+                    None => rsx! {
+                        a {
+                            class: "cursor-pointer text-sky-500 text-sm",
+                            onclick: move |_| {
+                                let array = array.clone();
+                                estimate.set(Some((array.clone(), None)));
+                                // Scanning every value takes a while on large arrays, so do it in a
+                                // task that hands control back to the webview between batches.
+                                async move {
+                                    let result = estimate_in_batches(&array)
+                                        .await
+                                        .map_err(|err| err.to_string());
+                                    estimate.set(Some((array, Some(result))));
+                                }
+                            },
+                            "Estimate"
+                        }
+                    },
+                    Some(None) => rsx! {
+                        p { class: "font-sans text-sm italic", "Estimating…" }
+                    },
+                    Some(Some(Ok(distinct))) => rsx! {
+                        p { class: "block font-sans font-bold text-sm antialiased leading-normal",
+                            "~{distinct}"
+                        }
+                    },
+                    Some(Some(Err(error))) => rsx! {
+                        p { class: "block font-mono text-sm text-red-700", "{error}" }
+                    },
+                }
+            }
+
+            if let Some(dict_size) = dict_size {
+                p { class: "font-regular font-sans text-sm italic text-slate-300/30",
+                    "(dictionary holds {dict_size} values)"
+                }
+            }
+        }
+    }
+}
+
+/// Rows scanned between handing control back to the webview while estimating.
+const ESTIMATE_BATCH_ROWS: usize = 1 << 16;
+
+/// Estimate the number of distinct values of an array a batch of rows at a time.
+async fn estimate_in_batches(array: &ArrayData) -> VortexResult<usize> {
+    let mut sketch = HyperLogLog::default();
+    for start in (0..array.len()).step_by(ESTIMATE_BATCH_ROWS) {
+        yield_to_ui().await;
+        let end = (start + ESTIMATE_BATCH_ROWS).min(array.len());
+        sketch.insert_array(&slice(array, start, end)?)?;
+    }
+    Ok(sketch.estimate())
+}
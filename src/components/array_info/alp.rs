@@ -1,4 +1,3 @@
-use crate::components::Heading;
 use crate::SharedPtr;
 use dioxus::prelude::*;
 use vortex::alp::{ALPArray, Exponents};
@@ -8,8 +7,6 @@ pub fn ALPInfo(array: SharedPtr<ALPArray>) -> Element {
     let Exponents { e, f } = array.exponents();
 
     rsx! {
-        Heading { text: "ALP Encoding Parameters" }
-
         div { class: "relative flex flex-col max-w-7/12 bg-clip-border",
             table { class: "table-auto w-full min-w-max text-left border-collapse",
                 tbody { class: "border-b border-1 border-zinc-50/10",
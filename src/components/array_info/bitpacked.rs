@@ -1,15 +1,13 @@
 use dioxus::prelude::*;
 use vortex::fastlanes::BitPackedArray;
 
-use crate::{components::Heading, SharedPtr};
+use crate::SharedPtr;
 
 /// Encoding info for `BitPackedEncoding`.
 #[component]
 pub fn BitPackedInfo(array: SharedPtr<BitPackedArray>) -> Element {
     let bit_width = array.bit_width();
     rsx! {
-        Heading { text: "FastLanes Bit-packed Encoding" }
-
         div { class: "relative flex flex-col max-w-7/12 bg-clip-border",
             table { class: "table-auto w-full min-w-max text-left border-collapse",
                 tbody { class: "border-b border-1 border-zinc-50/10",
@@ -1,11 +1,11 @@
 use dioxus::prelude::*;
 use vortex::array::ConstantArray;
 
-use crate::SharedPtr;
+use crate::{scalar_fmt::format_scalar, SharedPtr};
 
 #[component]
 pub fn ConstantInfo(array: SharedPtr<ConstantArray>) -> Element {
-    let const_value = array.scalar();
+    let const_value = format_scalar(&array.scalar());
 
     rsx! {
         div { class: "relative flex flex-col max-w-7/12 bg-clip-border",
@@ -1,15 +1,13 @@
 use dioxus::prelude::*;
 use vortex::{compute::scalar_at, dict::DictArray, ArrayDType};
 
-use crate::{components::Heading, SharedPtr};
+use crate::{scalar_fmt::format_scalar, SharedPtr};
 
 #[component]
 pub fn DictInfo(array: SharedPtr<DictArray>) -> Element {
     let mut show_dictionary = use_signal(|| false);
     let num_values = array.values().len();
     rsx! {
-        Heading { text: "Dictionary Encoding" }
-
         a {
             // Styling.
             class: "text-lg flex flex-row cursor-pointer gap-x-1",
@@ -45,7 +43,7 @@ pub fn DictionaryView(array: SharedPtr<DictArray>) -> Element {
 
     for i in 0..dict_values.len() {
         let value = scalar_at(&dict_values, i)?;
-        dict_strings.push(format!("{value}"));
+        dict_strings.push(format_scalar(&value));
     }
 
     rsx! {
@@ -1,17 +1,15 @@
 use dioxus::prelude::*;
 use vortex::fastlanes::FoRArray;
 
-use crate::{components::Heading, SharedPtr};
+use crate::{scalar_fmt::format_scalar, SharedPtr};
 
 /// Extra information for the Frame of Reference (FoR) encoding.
 #[component]
 pub fn FrameOfReferenceInfo(array: SharedPtr<FoRArray>) -> Element {
-    let reference = array.reference_scalar();
+    let reference = format_scalar(&array.reference_scalar());
     let shift = array.shift();
 
     rsx! {
-        Heading { text: "FastLanes Frame-of-reference Encoding" }
-
         div { class: "relative flex flex-col max-w-7/12 bg-clip-border",
             table { class: "table-auto w-full min-w-max text-left border-collapse",
                 tbody { class: "border-b border-1 border-zinc-50/10",
@@ -1,7 +1,7 @@
 use dioxus::prelude::*;
 use vortex::{fsst::FSSTArray, IntoArrayVariant};
 
-use crate::{components::Heading, SharedPtr};
+use crate::SharedPtr;
 
 /// Information view for `FSSTArray`. Provides access to the symbol table.
 #[component]
@@ -9,8 +9,6 @@ pub fn FSSTInfo(array: SharedPtr<FSSTArray>) -> Element {
     let mut show_symbol_table = use_signal(|| false);
 
     rsx! {
-        Heading { text: "FSST Encoding" }
-
         // Short description of the symbol table child
         a {
             // Styling.
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use dioxus::prelude::*;
+use vortex::{
+    alp::{ALPArray, ALPEncoding, Exponents},
+    array::{ConstantArray, ConstantEncoding},
+    dict::{DictArray, DictEncoding},
+    encoding::{Encoding, EncodingId},
+    error::VortexResult,
+    fastlanes::{BitPackedArray, BitPackedEncoding, FoRArray, FoREncoding},
+    fsst::{FSSTArray, FSSTEncoding},
+    runend::{RunEndArray, RunEndEncoding},
+    ArrayData,
+};
+
+use super::{
+    alp::ALPInfo, bitpacked::BitPackedInfo, constant::ConstantInfo, dict::DictInfo,
+    frame_of_reference::FrameOfReferenceInfo, fsst::FSSTInfo, runend::RunEndInfo,
+};
+use crate::{scalar_fmt::format_scalar, SharedPtr};
+
+/// Build a [`DetailRenderer`] that downcasts the array before handing it to a typed component.
+macro_rules! typed_detail {
+    ($array:ty, $component:ident) => {
+        |array: SharedPtr<ArrayData>| -> Element {
+            let array = SharedPtr(Arc::new(<$array>::try_from((*array).clone())?));
+            rsx! {
+                $component { array }
+            }
+        }
+    };
+}
+
+/// Renders the details of an array of a registered encoding.
+///
+/// Rendered as its own component, so it is free to use hooks.
+pub type DetailRenderer = fn(SharedPtr<ArrayData>) -> Element;
+
+/// Computes a few headline metrics of an array, as label and value pairs.
+pub type SummaryMetrics = fn(&ArrayData) -> VortexResult<Vec<(String, String)>>;
+
+/// Everything the explorer knows how to show for one encoding.
+#[derive(Clone)]
+pub struct EncodingPanel {
+    pub title: String,
+    pub detail: DetailRenderer,
+    /// Encoding parameters, shown as extra rows in the summary table of the node and included in
+    /// reports.
+    pub summary: Option<SummaryMetrics>,
+}
+
+/// Encoding-specific panels, keyed by encoding id.
+///
+/// Provided as context at the root of the app. Code embedding the explorer can register panels for
+/// its own encodings, or replace the built-in ones.
+#[derive(Clone, Default)]
+pub struct EncodingRegistry {
+    panels: Arc<HashMap<EncodingId, EncodingPanel>>,
+}
+
+// Registries are only swapped out wholesale, so comparing by identity is enough.
+impl PartialEq for EncodingRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.panels, &other.panels)
+    }
+}
+
+impl EncodingRegistry {
+    /// A registry with panels for the encodings that ship with Vortex.
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        registry.register(
+            FSSTEncoding::ID,
+            EncodingPanel {
+                title: "FSST Encoding".to_string(),
+                detail: typed_detail!(FSSTArray, FSSTInfo),
+                summary: Some(|array| {
+                    let symbols = FSSTArray::try_from(array.clone())?.symbols().len();
+                    Ok(vec![("Symbols".to_string(), symbols.to_string())])
+                }),
+            },
+        );
+        registry.register(
+            DictEncoding::ID,
+            EncodingPanel {
+                title: "Dictionary Encoding".to_string(),
+                detail: typed_detail!(DictArray, DictInfo),
+                summary: Some(|array| {
+                    let values = DictArray::try_from(array.clone())?.values().len();
+                    Ok(vec![("Dictionary Size".to_string(), values.to_string())])
+                }),
+            },
+        );
+        registry.register(
+            FoREncoding::ID,
+            EncodingPanel {
+                title: "FastLanes Frame-of-reference Encoding".to_string(),
+                detail: typed_detail!(FoRArray, FrameOfReferenceInfo),
+                summary: Some(|array| {
+                    let array = FoRArray::try_from(array.clone())?;
+                    Ok(vec![
+                        (
+                            "Reference".to_string(),
+                            format_scalar(&array.reference_scalar()),
+                        ),
+                        ("Shift".to_string(), array.shift().to_string()),
+                    ])
+                }),
+            },
+        );
+        registry.register(
+            BitPackedEncoding::ID,
+            EncodingPanel {
+                title: "FastLanes Bit-packed Encoding".to_string(),
+                detail: typed_detail!(BitPackedArray, BitPackedInfo),
+                summary: Some(|array| {
+                    let bit_width = BitPackedArray::try_from(array.clone())?.bit_width();
+                    Ok(vec![("Bit-Width".to_string(), bit_width.to_string())])
+                }),
+            },
+        );
+        registry.register(
+            RunEndEncoding::ID,
+            EncodingPanel {
+                title: "Run-End Encoding".to_string(),
+                detail: typed_detail!(RunEndArray, RunEndInfo),
+                summary: Some(|array| {
+                    let runs = RunEndArray::try_from(array.clone())?.ends().len();
+                    Ok(vec![("Runs".to_string(), runs.to_string())])
+                }),
+            },
+        );
+        registry.register(
+            ConstantEncoding::ID,
+            EncodingPanel {
+                title: "Constant Encoding".to_string(),
+                detail: typed_detail!(ConstantArray, ConstantInfo),
+                summary: Some(|array| {
+                    let value = format_scalar(&ConstantArray::try_from(array.clone())?.scalar());
+                    Ok(vec![("Constant Value".to_string(), value)])
+                }),
+            },
+        );
+        registry.register(
+            ALPEncoding::ID,
+            EncodingPanel {
+                title: "ALP Encoding".to_string(),
+                detail: typed_detail!(ALPArray, ALPInfo),
+                summary: Some(|array| {
+                    let Exponents { e, f } = ALPArray::try_from(array.clone())?.exponents();
+                    Ok(vec![
+                        ("Exponent (e)".to_string(), e.to_string()),
+                        ("Factor (f)".to_string(), f.to_string()),
+                    ])
+                }),
+            },
+        );
+        registry
+    }
+
+    /// Register the panel for an encoding, replacing any existing one.
+    pub fn register(&mut self, id: EncodingId, panel: EncodingPanel) {
+        Arc::make_mut(&mut self.panels).insert(id, panel);
+    }
+
+    pub fn get(&self, id: EncodingId) -> Option<&EncodingPanel> {
+        self.panels.get(&id)
+    }
+}
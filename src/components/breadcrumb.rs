@@ -1,43 +1,138 @@
 use crate::{HistoryItem, HistoryStack};
 use dioxus::prelude::*;
-use dioxus_free_icons::icons::hi_solid_icons::HiChevronRight;
+use dioxus_free_icons::icons::hi_solid_icons::{
+    HiArrowLeft, HiArrowRight, HiArrowUp, HiChevronRight,
+};
 use dioxus_free_icons::Icon;
 
 #[component]
 pub fn Breadcrumbs(history_stack: Signal<HistoryStack>) -> Element {
     let last_idx = history_stack().len().checked_sub(1).unwrap_or_default();
     rsx! {
-        div { class: "flex flex-row items-center overflow-x-auto",
-            if let Some(HistoryItem { name, .. }) = history_stack().iter().next() {
-                p {
-                    class: "text-md font-bold",
-                    class: if last_idx > 0 { "hover:text-sky-500 cursor-pointer" },
-
-                    onclick: move |_| {
-                        if last_idx > 0 {
-                            history_stack.write().goto(0);
-                        }
-                    },
-                    "{name}"
+        div { class: "flex flex-row items-center gap-x-4",
+            NavigationButtons { history_stack }
+
+            div { class: "flex flex-row items-center overflow-x-auto",
+                if let Some(HistoryItem { name, .. }) = history_stack().iter().next() {
+                    p {
+                        class: "text-md font-bold",
+                        class: if last_idx > 0 { "hover:text-sky-500 cursor-pointer" },
+
+                        onclick: move |_| {
+                            if last_idx > 0 {
+                                history_stack.write().goto(0);
+                            }
+                        },
+                        "{name}"
+                    }
                 }
-            }
-            for (idx , item) in history_stack().iter().enumerate().skip(1) {
-                div {
-                    Icon { width: 30, height: 30, icon: HiChevronRight }
+                for (idx , item) in history_stack().iter().enumerate().skip(1) {
+                    div {
+                        Icon { width: 30, height: 30, icon: HiChevronRight }
+                    }
+
+                    // Make it clickable to revert back to this element in the tree instead.
+                    p {
+                        class: "text-md font-bold",
+                        class: if idx != last_idx { "hover:text-sky-500 cursor-pointer" },
+                        onclick: move |_| {
+                            if idx != last_idx {
+                                history_stack.write().goto(idx);
+                            }
+                        },
+
+                        "{item.name}"
+                    }
                 }
+            }
+
+            RecentNodes { history_stack }
+        }
+    }
+}
+
+/// Back, forward and up buttons, also available as Alt+Left, Alt+Right and Alt+Up.
+#[component]
+fn NavigationButtons(history_stack: Signal<HistoryStack>) -> Element {
+    let can_go_back = history_stack.read().can_go_back();
+    let can_go_forward = history_stack.read().can_go_forward();
+    let can_go_up = history_stack.read().len() > 1;
+
+    rsx! {
+        div { class: "flex flex-row items-center gap-x-1",
+            NavigationButton {
+                title: "Back (Alt+Left)",
+                enabled: can_go_back,
+                onclick: move |_| history_stack.write().back(),
+                Icon { width: 20, height: 20, icon: HiArrowLeft }
+            }
+            NavigationButton {
+                title: "Forward (Alt+Right)",
+                enabled: can_go_forward,
+                onclick: move |_| history_stack.write().forward(),
+                Icon { width: 20, height: 20, icon: HiArrowRight }
+            }
+            NavigationButton {
+                title: "Up (Alt+Up)",
+                enabled: can_go_up,
+                onclick: move |_| history_stack.write().up(),
+                Icon { width: 20, height: 20, icon: HiArrowUp }
+            }
+        }
+    }
+}
+
+#[component]
+fn NavigationButton(
+    title: String,
+    enabled: bool,
+    onclick: EventHandler<()>,
+    children: Element,
+) -> Element {
+    rsx! {
+        button {
+            class: "p-1 rounded",
+            class: if enabled { "hover:bg-neutral-700 hover:text-sky-500 cursor-pointer" } else { "opacity-30" },
+            title,
+            disabled: !enabled,
+            onclick: move |_| onclick.call(()),
+            {children}
+        }
+    }
+}
+
+/// Dropdown of the most recently visited nodes.
+#[component]
+fn RecentNodes(history_stack: Signal<HistoryStack>) -> Element {
+    let recent: Vec<String> = history_stack
+        .read()
+        .recent()
+        .map(|path| {
+            if path.is_empty() {
+                "/".to_string()
+            } else {
+                path.join("/")
+            }
+        })
+        .collect();
+
+    // The first entry is the current node, so there is nothing to jump to yet.
+    if recent.len() < 2 {
+        return rsx! {};
+    }
 
-                // Make it clickable to revert back to this element in the tree instead.
-                p {
-                    class: "text-md font-bold",
-                    class: if idx != last_idx { "hover:text-sky-500 cursor-pointer" },
-                    onclick: move |_| {
-                        if idx != last_idx {
-                            history_stack.write().goto(idx);
-                        }
-                    },
-
-                    "{item.name}"
+    rsx! {
+        select {
+            class: "ml-auto px-2 py-1 rounded bg-neutral-800 font-mono text-sm",
+            value: "",
+            onchange: move |evt| {
+                if let Ok(idx) = evt.value().parse::<usize>() {
+                    history_stack.write().goto_recent(idx);
                 }
+            },
+            option { value: "", disabled: true, selected: true, "Recently visited" }
+            for (idx , path) in recent.into_iter().enumerate().skip(1) {
+                option { value: "{idx}", "{path}" }
             }
         }
     }
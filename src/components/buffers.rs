@@ -0,0 +1,256 @@
+use std::ops::Range;
+
+use bytes::Bytes;
+use dioxus::prelude::*;
+use vortex::ArrayData;
+
+use crate::{
+    components::{
+        hex::{HexDump, ROW_WIDTH},
+        Heading,
+    },
+    SharedPtr,
+};
+
+/// Bytes shown per page of the hex dump.
+const PAGE_SIZE: usize = 32 * ROW_WIDTH;
+
+/// Where a buffer lives in memory and in the file.
+#[derive(Clone, Copy, PartialEq)]
+struct BufferInfo {
+    len: usize,
+    alignment: usize,
+    /// Offset of the buffer within the file, if it is a view into the file's bytes.
+    file_offset: Option<usize>,
+}
+
+/// The buffers owned by a node, with a hex dump of each.
+#[component]
+pub fn BuffersPanel(array: SharedPtr<ArrayData>, contents: Option<SharedPtr<Bytes>>) -> Element {
+    // Arrays own at most one buffer, their children own the rest.
+    let buffers: Vec<BufferInfo> = array
+        .buffer()
+        .into_iter()
+        .map(|buffer| {
+            let bytes = buffer.as_slice();
+            BufferInfo {
+                len: bytes.len(),
+                alignment: alignment(bytes),
+                file_offset: contents
+                    .as_ref()
+                    .and_then(|contents| file_offset(contents, bytes)),
+            }
+        })
+        .collect();
+
+    rsx! {
+        Heading { text: "Buffers" }
+
+        if buffers.is_empty() {
+            p { class: "p-4 font-regular font-sans text-sm italic text-slate-300/30",
+                "This node does not own any buffers"
+            }
+        } else {
+            table { class: "table-auto w-full min-w-max text-left border-collapse",
+                thead { class: "bg-neutral-700 border-b border-1 border-zinc-50/10",
+                    tr {
+                        for label in ["Buffer", "Size", "Alignment", "File Offset"] {
+                            th { class: "p-2",
+                                p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
+                                    "{label}"
+                                }
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    for (idx , info) in buffers.iter().enumerate() {
+                        tr { class: "font-normal hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
+                            td { class: "p-2 font-mono text-sm", "{idx}" }
+                            td { class: "p-2 font-mono text-sm",
+                                "{humansize::format_size(info.len, humansize::BINARY)} ({info.len} bytes)"
+                            }
+                            td { class: "p-2 font-mono text-sm", "{info.alignment}" }
+                            td { class: "p-2 font-mono text-sm",
+                                if let Some(offset) = info.file_offset {
+                                    "{offset} (0x{offset:x})"
+                                } else {
+                                    "unknown"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            for info in buffers {
+                BufferViewer { array: array.clone(), info }
+            }
+        }
+    }
+}
+
+/// Paged hex dump of the buffer of an array, highlighting a range of bytes entered by the user.
+#[component]
+fn BufferViewer(array: SharedPtr<ArrayData>, info: BufferInfo) -> Element {
+    let mut page = use_signal(|| 0usize);
+    let mut highlight_input = use_signal(String::new);
+
+    let Some(buffer) = array.buffer() else {
+        return rsx! {};
+    };
+    let pages = page_count(info.len);
+    let (current_page, shown) = page_bounds(info.len, page());
+    let start = shown.start;
+    // Only copy out the page being shown.
+    let bytes = Bytes::copy_from_slice(&buffer.as_slice()[shown]);
+    let highlight = parse_range(&highlight_input.read(), info.len);
+
+    rsx! {
+        div { class: "flex flex-col gap-y-2 py-4",
+            div { class: "flex flex-row items-center gap-x-4 font-sans text-sm",
+                a {
+                    class: "cursor-pointer hover:text-sky-500",
+                    class: if current_page == 0 { "opacity-30" },
+                    onclick: move |_| page.set(current_page.saturating_sub(1)),
+                    "◀ Previous"
+                }
+                span { "Page {current_page + 1} of {pages}" }
+                a {
+                    class: "cursor-pointer hover:text-sky-500",
+                    class: if current_page + 1 == pages { "opacity-30" },
+                    onclick: move |_| page.set((current_page + 1).min(pages - 1)),
+                    "Next ▶"
+                }
+                input {
+                    class: "px-2 py-1 rounded bg-neutral-800 font-mono text-sm",
+                    placeholder: "Highlight, e.g. 16..32",
+                    value: "{highlight_input}",
+                    oninput: move |evt| {
+                        let value = evt.value();
+                        // Jump to the page holding the start of the highlighted range.
+                        if let Some(range) = parse_range(&value, info.len) {
+                            page.set(range.start / PAGE_SIZE);
+                        }
+                        highlight_input.set(value);
+                    },
+                }
+                if let Some(file_offset) = info.file_offset {
+                    span { class: "opacity-70", "Starts at file offset {file_offset}" }
+                }
+            }
+
+            HexDump { bytes, offset: start, highlight }
+        }
+    }
+}
+
+/// Number of pages needed to show a buffer of `len` bytes. Empty buffers still show one page.
+fn page_count(len: usize) -> usize {
+    len.div_ceil(PAGE_SIZE).max(1)
+}
+
+/// The page of a buffer of `len` bytes to show, clamped to the last page, and the bytes it covers.
+fn page_bounds(len: usize, page: usize) -> (usize, Range<usize>) {
+    let page = page.min(page_count(len) - 1);
+    let start = page * PAGE_SIZE;
+    (page, start..(start + PAGE_SIZE).min(len))
+}
+
+/// Parse a byte range like `16..32`, or a single offset like `16`, within a buffer of `len` bytes.
+///
+/// Ranges starting past the end of the buffer are rejected, and ranges running past it are cut
+/// short.
+fn parse_range(input: &str, len: usize) -> Option<Range<usize>> {
+    let input = input.trim();
+    let (start, end) = match input.split_once("..") {
+        Some((start, end)) => {
+            let start: usize = start.trim().parse().ok()?;
+            let end: usize = end.trim().parse().ok()?;
+            (start, end)
+        }
+        None => {
+            let offset: usize = input.parse().ok()?;
+            (offset, offset.saturating_add(1))
+        }
+    };
+    (start < end && start < len).then(|| start..end.min(len))
+}
+
+/// Position of `buffer` within the file, if it is a view into the file's bytes rather than a copy.
+fn file_offset(contents: &[u8], buffer: &[u8]) -> Option<usize> {
+    let file = contents.as_ptr_range();
+    let start = buffer.as_ptr();
+    (file.contains(&start) && buffer.len() <= file.end as usize - start as usize)
+        .then(|| start as usize - file.start as usize)
+}
+
+/// The largest power of two the start of the buffer is aligned to, capped at 4KiB.
+fn alignment(buffer: &[u8]) -> usize {
+    let address = buffer.as_ptr() as usize;
+    if address == 0 {
+        return 4096;
+    }
+    (1usize << address.trailing_zeros()).min(4096)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges() {
+        assert_eq!(parse_range("16..32", 64), Some(16..32));
+        assert_eq!(parse_range(" 16 .. 32 ", 64), Some(16..32));
+        assert_eq!(parse_range("16", 64), Some(16..17));
+    }
+
+    #[test]
+    fn malformed_ranges() {
+        for input in [
+            "", "abc", "16..", "..32", "-1", "1.5", "16...32", "32..16", "16..16",
+        ] {
+            assert_eq!(parse_range(input, 64), None, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn ranges_outside_the_buffer() {
+        assert_eq!(parse_range("64", 64), None);
+        assert_eq!(parse_range("64..128", 64), None);
+        assert_eq!(parse_range("0", 0), None);
+        assert_eq!(parse_range("32..128", 64), Some(32..64));
+        assert_eq!(parse_range(&usize::MAX.to_string(), 64), None);
+        assert_eq!(parse_range(&format!("0..{}", usize::MAX), 64), Some(0..64));
+    }
+
+    #[test]
+    fn pages() {
+        assert_eq!(page_count(0), 1);
+        assert_eq!(page_bounds(0, 0), (0, 0..0));
+        assert_eq!(page_count(PAGE_SIZE), 1);
+        assert_eq!(page_bounds(PAGE_SIZE, 0), (0, 0..PAGE_SIZE));
+        assert_eq!(page_count(PAGE_SIZE + 1), 2);
+    }
+
+    #[test]
+    fn last_partial_page() {
+        let len = 2 * PAGE_SIZE + 10;
+        assert_eq!(page_count(len), 3);
+        assert_eq!(page_bounds(len, 1), (1, PAGE_SIZE..2 * PAGE_SIZE));
+        assert_eq!(page_bounds(len, 2), (2, 2 * PAGE_SIZE..len));
+        // Pages past the end show the last one.
+        assert_eq!(page_bounds(len, 3), (2, 2 * PAGE_SIZE..len));
+        assert_eq!(page_bounds(len, usize::MAX), (2, 2 * PAGE_SIZE..len));
+    }
+
+    #[test]
+    fn file_offsets() {
+        let contents = vec![0u8; 64];
+        assert_eq!(file_offset(&contents, &contents), Some(0));
+        assert_eq!(file_offset(&contents, &contents[16..32]), Some(16));
+        assert_eq!(file_offset(&contents, &contents[63..]), Some(63));
+        // A copy of the bytes is not part of the file.
+        assert_eq!(file_offset(&contents, &contents[16..32].to_vec()), None);
+    }
+}
@@ -0,0 +1,459 @@
+use std::collections::BTreeSet;
+
+use dioxus::prelude::*;
+use vortex::ArrayData;
+
+use crate::{
+    components::{
+        array_info::EncodingRegistry, breadcrumb::Breadcrumbs, error::ReadErrorDetails, Heading,
+    },
+    error::ReadError,
+    file_util::{read_uploaded_file, ReaderConfig},
+    summary::{
+        diff_columns, encoding_label, encoding_tree, node_children, node_nbytes, ColumnDiff,
+        ColumnSummary, NodeSummary,
+    },
+    HistoryItem, HistoryStack, Tab,
+};
+
+/// Compare the open file against a second file.
+///
+/// Shows a picker for the second file until one has been opened.
+#[component]
+pub fn CompareMode(
+    file_name: String,
+    history_stack: Signal<HistoryStack>,
+    mut compare_name: Signal<String>,
+    mut compare_error: Signal<Option<ReadError>>,
+    mut compare_stack: Signal<HistoryStack>,
+    other_tabs: Vec<Tab>,
+) -> Element {
+    let config = use_context::<ReaderConfig>();
+    // Files already open in other tabs can be compared against directly.
+    let open_files: Vec<(String, HistoryItem)> = other_tabs
+        .iter()
+        .filter_map(|tab| {
+            Some((
+                tab.file_name.read().clone(),
+                tab.history_stack.read().root()?.clone(),
+            ))
+        })
+        .collect();
+
+    if compare_stack().is_empty() {
+        return rsx! {
+            if !open_files.is_empty() {
+                p { class: "py-4", "Compare {file_name} against an open file:" }
+                ul { class: "px-4",
+                    for (name , root) in open_files {
+                        li {
+                            a {
+                                class: "cursor-pointer text-sky-500 text-bold",
+                                onclick: {
+                                    let name = name.clone();
+                                    move |_| {
+                                        compare_name.set(name.clone());
+                                        compare_error.set(None);
+                                        let mut stack = compare_stack.write();
+                                        stack.clear();
+                                        stack.push(root.name.clone(), (*root.array).clone());
+                                    }
+                                },
+                                "{name}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            p { class: "py-4", "Select a second Vortex file to compare against {file_name}." }
+
+            input {
+                r#type: "file",
+                accept: ".vortex",
+                multiple: false,
+                onchange: move |evt| {
+                    let config = config.clone();
+                    async move {
+                        if let Some(file_engine) = evt.files() {
+                            read_uploaded_file(
+                                    file_engine,
+                                    compare_name,
+                                    config,
+                                    compare_error,
+                                    compare_stack,
+                                )
+                                .await;
+                        }
+                    }
+                },
+            }
+
+            if let Some(error) = compare_error() {
+                ReadErrorDetails { error }
+            }
+        };
+    }
+
+    rsx! {
+        div { class: "flex flex-row justify-end py-2",
+            a {
+                class: "cursor-pointer text-sky-500 text-sm",
+                onclick: move |_| {
+                    compare_stack.write().clear();
+                },
+                "Choose a different file"
+            }
+        }
+
+        CompareView {
+            left_name: file_name,
+            left: history_stack,
+            right_name: compare_name(),
+            right: compare_stack,
+        }
+    }
+}
+
+/// Side-by-side comparison of the current node of two files.
+#[component]
+pub fn CompareView(
+    left_name: String,
+    left: Signal<HistoryStack>,
+    right_name: String,
+    mut right: Signal<HistoryStack>,
+) -> Element {
+    let registry = use_context::<EncodingRegistry>();
+    let mut lockstep = use_signal(|| true);
+
+    // In lockstep mode the right-hand file follows wherever the left-hand file navigates to.
+    use_effect(move || {
+        if lockstep() {
+            let path = left.read().path();
+            if right.peek().path() != path {
+                right.write().goto_path(&path);
+            }
+        }
+    });
+
+    let (Some(left_item), Some(right_item)) = (
+        left.read().current().cloned(),
+        right.read().current().cloned(),
+    ) else {
+        return rsx! {};
+    };
+    let left_summary = NodeSummary::try_new(&left_item.array, &registry)?;
+    let right_summary = NodeSummary::try_new(&right_item.array, &registry)?;
+
+    let mut rows = vec![
+        (
+            "Encoding".to_string(),
+            left_summary.encoding.clone(),
+            right_summary.encoding.clone(),
+        ),
+        (
+            "DType".to_string(),
+            left_summary.dtype.clone(),
+            right_summary.dtype.clone(),
+        ),
+        (
+            "Row Count".to_string(),
+            left_summary.len.to_string(),
+            right_summary.len.to_string(),
+        ),
+        (
+            "Size".to_string(),
+            humansize::format_size(left_summary.nbytes, humansize::BINARY),
+            humansize::format_size(right_summary.nbytes, humansize::BINARY),
+        ),
+        (
+            "Null Count".to_string(),
+            left_summary.null_count.to_string(),
+            right_summary.null_count.to_string(),
+        ),
+        (
+            "Encoding Tree".to_string(),
+            encoding_tree(&left_item.array),
+            encoding_tree(&right_item.array),
+        ),
+    ];
+    let stat_names: BTreeSet<&String> = left_summary
+        .stats
+        .keys()
+        .chain(right_summary.stats.keys())
+        .collect();
+    for stat in stat_names {
+        rows.push((
+            stat.clone(),
+            left_summary.stats.get(stat).cloned().unwrap_or_default(),
+            right_summary.stats.get(stat).cloned().unwrap_or_default(),
+        ));
+    }
+
+    // The per-column breakdown only makes sense when both sides are at the root of their file.
+    let column_diffs = if left.read().len() == 1 && right.read().len() == 1 {
+        Some(diff_columns(&left_item.array, &right_item.array)?)
+    } else {
+        None
+    };
+
+    rsx! {
+        label { class: "flex flex-row items-center gap-x-2 py-2 text-sm",
+            input {
+                r#type: "checkbox",
+                checked: lockstep(),
+                onchange: move |evt| {
+                    lockstep.set(evt.checked());
+                },
+            }
+            "Navigate both files in lockstep"
+        }
+
+        div { class: "grid grid-cols-2 gap-x-8",
+            Breadcrumbs { history_stack: left }
+            Breadcrumbs { history_stack: right }
+        }
+
+        Heading { text: "Current Node" }
+        DiffTable {
+            left_name: left_name.clone(),
+            right_name: right_name.clone(),
+            rows,
+        }
+
+        if let Some(column_diffs) = column_diffs {
+            Heading { text: "Columns" }
+            ColumnDiffTable { left_name, right_name, column_diffs }
+        }
+
+        CompareChildren { left, right, lockstep }
+    }
+}
+
+/// Table with one row per property, highlighting the ones that differ between the two files.
+#[component]
+fn DiffTable(
+    left_name: String,
+    right_name: String,
+    rows: Vec<(String, String, String)>,
+) -> Element {
+    rsx! {
+        div { class: "relative flex flex-col w-full overflow-x-auto bg-clip-border",
+            table { class: "table-fixed w-full text-left border-collapse",
+                thead { class: "bg-neutral-700 border-b border-1 border-zinc-50/10",
+                    tr {
+                        th { class: "p-4 w-48" }
+                        th { class: "p-4",
+                            p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
+                                "{left_name}"
+                            }
+                        }
+                        th { class: "p-4",
+                            p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
+                                "{right_name}"
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    for (label , left_value , right_value) in rows {
+                        tr {
+                            class: "font-normal hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
+                            class: if left_value != right_value { "bg-amber-500/20" },
+                            td { class: "p-1",
+                                p { class: "block font-sans font-bold text-sm antialiased leading-normal",
+                                    "{label}"
+                                }
+                            }
+                            td { class: "p-1",
+                                p { class: "block font-mono text-sm antialiased leading-normal break-all",
+                                    "{left_value}"
+                                }
+                            }
+                            td { class: "p-1",
+                                p { class: "block font-mono text-sm antialiased leading-normal break-all",
+                                    "{right_value}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Schema, size and encodings of each top-level column in both files.
+#[component]
+fn ColumnDiffTable(
+    left_name: String,
+    right_name: String,
+    column_diffs: Vec<ColumnDiff>,
+) -> Element {
+    rsx! {
+        div { class: "relative flex flex-col w-full overflow-x-auto bg-clip-border",
+            table { class: "table-fixed w-full text-left border-collapse",
+                thead { class: "bg-neutral-700 border-b border-1 border-zinc-50/10",
+                    tr {
+                        th { class: "p-4 w-48",
+                            p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
+                                "Column"
+                            }
+                        }
+                        th { class: "p-4",
+                            p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
+                                "{left_name}"
+                            }
+                        }
+                        th { class: "p-4",
+                            p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
+                                "{right_name}"
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    for diff in column_diffs {
+                        tr {
+                            class: "font-normal align-top hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
+                            class: if diff.is_changed() { "bg-amber-500/20" },
+                            td { class: "p-1",
+                                p { class: "block font-sans font-bold text-sm antialiased leading-normal",
+                                    "{diff.name}"
+                                }
+                            }
+                            ColumnSummaryCell { summary: diff.left.clone() }
+                            ColumnSummaryCell { summary: diff.right.clone() }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ColumnSummaryCell(summary: Option<ColumnSummary>) -> Element {
+    let Some(summary) = summary else {
+        return rsx! {
+            td { class: "p-1",
+                p { class: "block font-sans text-sm italic text-slate-300/30", "missing" }
+            }
+        };
+    };
+    let size = humansize::format_size(summary.nbytes, humansize::BINARY);
+
+    rsx! {
+        td { class: "p-1",
+            p { class: "block font-mono text-sm antialiased leading-normal", "{summary.dtype}" }
+            p { class: "block font-mono text-sm antialiased leading-normal",
+                "{summary.len} rows, {size}"
+            }
+            for encoding in summary.encodings.iter() {
+                p { class: "block font-mono text-xs antialiased leading-normal opacity-70 break-all",
+                    "{encoding}"
+                }
+            }
+        }
+    }
+}
+
+/// The children of the current node of both files, matched up by name.
+#[component]
+fn CompareChildren(
+    mut left: Signal<HistoryStack>,
+    mut right: Signal<HistoryStack>,
+    lockstep: Signal<bool>,
+) -> Element {
+    let left_children = left
+        .read()
+        .current()
+        .map(|item| node_children(&item.array))
+        .unwrap_or_default();
+    let right_children = right
+        .read()
+        .current()
+        .map(|item| node_children(&item.array))
+        .unwrap_or_default();
+
+    let mut names: Vec<String> = left_children.iter().map(|(name, _)| name.clone()).collect();
+    for (name, _) in right_children.iter() {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+    if names.is_empty() {
+        return rsx! {};
+    }
+
+    let describe = |children: &[(String, ArrayData)], name: &str| {
+        children
+            .iter()
+            .find(|(child_name, _)| child_name == name)
+            .map(|(_, child)| {
+                format!(
+                    "{} — {} rows, {}",
+                    encoding_label(child),
+                    child.len(),
+                    humansize::format_size(node_nbytes(child), humansize::BINARY)
+                )
+            })
+    };
+    let rows: Vec<(String, Option<String>, Option<String>)> = names
+        .into_iter()
+        .map(|name| {
+            let left_desc = describe(&left_children, &name);
+            let right_desc = describe(&right_children, &name);
+            (name, left_desc, right_desc)
+        })
+        .collect();
+
+    rsx! {
+        Heading { text: "Child Arrays" }
+
+        table { class: "table-fixed w-full text-left border-collapse",
+            tbody { class: "border-b border-1 border-zinc-50/10",
+                for (name , left_desc , right_desc) in rows {
+                    tr {
+                        class: "font-normal border-b border-1 border-zinc-50/10",
+                        class: if left_desc != right_desc { "bg-amber-500/20" },
+                        td { class: "p-2 w-48",
+                            p { class: "block font-sans text-sm antialiased leading-normal",
+                                "{name}"
+                            }
+                        }
+                        td {
+                            class: "p-2",
+                            class: if left_desc.is_some() { "cursor-pointer hover:bg-neutral-800/75 hover:text-sky-500" },
+                            onclick: {
+                                let name = name.clone();
+                                move |_| {
+                                    // In lockstep mode the right-hand side follows automatically.
+                                    left.write().descend(&[name.clone()]);
+                                }
+                            },
+                            p { class: "block font-mono text-sm antialiased leading-normal",
+                                {left_desc.clone().unwrap_or_else(|| "missing".to_string())}
+                            }
+                        }
+                        td {
+                            class: "p-2",
+                            class: if right_desc.is_some() && !lockstep() { "cursor-pointer hover:bg-neutral-800/75 hover:text-sky-500" },
+                            onclick: {
+                                let name = name.clone();
+                                move |_| {
+                                    if !lockstep() {
+                                        right.write().descend(&[name.clone()]);
+                                    }
+                                }
+                            },
+                            p { class: "block font-mono text-sm antialiased leading-normal",
+                                {right_desc.clone().unwrap_or_else(|| "missing".to_string())}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use bytes::Bytes;
+use dioxus::prelude::*;
+
+use crate::{
+    components::{
+        hex::{HexDump, ROW_WIDTH},
+        Heading,
+    },
+    diagnostics::{diagnose, Diagnosis},
+    file_util::ReaderConfig,
+    SharedPtr,
+};
+
+/// Bytes shown on either side of the offset where parsing stopped.
+const CONTEXT_BYTES: usize = 256;
+
+/// Parse a file section by section and show where parsing stopped.
+#[component]
+pub fn DiagnosticsView(contents: SharedPtr<Bytes>) -> Element {
+    let config = use_context::<ReaderConfig>();
+    let diagnosis = use_resource(use_reactive!(|contents| {
+        let config = config.clone();
+        async move { diagnose((*contents).clone(), &config).await }
+    }));
+
+    let Some(diagnosis) = diagnosis() else {
+        return rsx! {
+            Heading { text: "Diagnostics" }
+            p { class: "font-sans text-sm italic", "Parsing file…" }
+        };
+    };
+
+    rsx! {
+        Heading { text: "Diagnostics" }
+
+        DiagnosisTable { diagnosis: diagnosis.clone() }
+
+        if let Some(failed_at) = diagnosis.failed_at {
+            FailureContext { contents, diagnosis, failed_at }
+        } else if diagnosis.failed() {
+            p { class: "py-4 font-sans text-sm",
+                "Every section of the file could be parsed, but its data could not be decoded."
+            }
+        } else {
+            p { class: "py-4 font-sans text-sm", "Every section of the file could be read." }
+        }
+    }
+}
+
+#[component]
+fn DiagnosisTable(diagnosis: Diagnosis) -> Element {
+    rsx! {
+        table { class: "table-auto w-full min-w-max text-left border-collapse",
+            tbody {
+                for step in diagnosis.steps {
+                    tr { class: "font-normal align-top hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
+                        td { class: "p-2",
+                            if step.result.is_ok() {
+                                span { class: "text-green-500", "✓" }
+                            } else {
+                                span { class: "text-red-700", "✗" }
+                            }
+                        }
+                        td { class: "p-2",
+                            p { class: "block font-sans font-bold text-sm antialiased leading-normal",
+                                "{step.name}"
+                            }
+                        }
+                        td { class: "p-2",
+                            p { class: "block font-mono text-sm antialiased leading-normal opacity-70",
+                                if let Some(range) = &step.range {
+                                    "{range.start}..{range.end}"
+                                }
+                            }
+                        }
+                        td { class: "p-2",
+                            {
+                                match &step.result {
+                                    Ok(detail) => rsx! {
+                                        p { class: "block font-mono text-sm antialiased leading-normal whitespace-pre-wrap",
+                                            "{detail}"
+                                        }
+                                    },
+                                    Err(error) => rsx! {
+                                        p { class: "block font-mono text-sm antialiased leading-normal whitespace-pre-wrap text-red-700",
+                                            "{error}"
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Hex view of the bytes around the section that could not be parsed.
+#[component]
+fn FailureContext(contents: SharedPtr<Bytes>, diagnosis: Diagnosis, failed_at: usize) -> Element {
+    let highlight = diagnosis
+        .steps
+        .last()
+        .and_then(|step| step.range.clone())
+        .filter(|range| !range.is_empty());
+
+    // Align the window to whole rows.
+    let start = failed_at.saturating_sub(CONTEXT_BYTES) / ROW_WIDTH * ROW_WIDTH;
+    let end = (failed_at + CONTEXT_BYTES).min(contents.len());
+
+    rsx! {
+        p { class: "py-4 font-sans text-sm",
+            "Parsing stopped at offset {failed_at} of {diagnosis.file_size} bytes."
+        }
+
+        HexDump { bytes: contents.slice(start..end), offset: start, highlight }
+    }
+}
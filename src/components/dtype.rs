@@ -1,76 +1,149 @@
+use std::sync::Arc;
+
 use dioxus::prelude::*;
-use vortex::{dtype::DType, error::vortex_err};
+use vortex::{dtype::DType, ArrayDType, ArrayData};
 
-use crate::components::Heading;
+use crate::{components::Heading, find_child, HistoryStack, SharedPtr};
 
 /// Component to display DType information for an Array.
+///
+/// The DType is rendered as a collapsible tree, with nested structs, lists and extension types
+/// expanded in place. Clicking a field that maps onto a child array navigates to that child.
 #[component]
-pub fn DTypeInfo(dtype: DType) -> Element {
-    let inner = if dtype.is_struct() {
-        rsx! {
-            SchemaTable { dtype }
-        }
-    } else {
-        let stringified = format!("{dtype}");
-        rsx! {
-            p { "{stringified}" }
-        }
-    };
+pub fn DTypeInfo(array: SharedPtr<ArrayData>, history_stack: Signal<HistoryStack>) -> Element {
+    let dtype = array.dtype().clone();
 
     rsx! {
         Heading { text: "Schema" }
 
-        {inner}
+        div { class: "flex flex-col font-mono text-sm",
+            DTypeNode {
+                name: None,
+                dtype,
+                array: Some(array),
+                path: Vec::new(),
+                history_stack,
+            }
+        }
     }
 }
 
+/// A single level of the DType tree, along with all of its nested children.
+///
+/// `path` holds the child names leading from the current array to this node, and `array` the child
+/// array they lead to, if there is one.
 #[component]
-pub fn SchemaTable(dtype: DType) -> Element {
-    let field_names = dtype
-        .as_struct()
-        .ok_or(vortex_err!("SchemaTable must receive a StructDType"))?
-        .names();
-    let field_types = dtype
-        .as_struct()
-        .ok_or(vortex_err!("SchemaTable must receive a StructDType"))?
-        .dtypes();
-    let names_and_types = field_names.iter().zip(field_types.iter());
+fn DTypeNode(
+    name: Option<String>,
+    dtype: DType,
+    array: Option<SharedPtr<ArrayData>>,
+    path: Vec<String>,
+    mut history_stack: Signal<HistoryStack>,
+) -> Element {
+    // Top-level node starts expanded, nested nodes start collapsed.
+    let mut expanded = use_signal(|| path.is_empty());
+    let children = nested_dtypes(&dtype);
+    let has_children = !children.is_empty();
+
+    // Only nodes which resolve to a real child array are navigable. Encoded arrays (e.g. chunked
+    // or dictionary-encoded structs) will not necessarily have children matching the schema.
+    let navigable = !path.is_empty() && array.is_some();
+
+    let nullability = if dtype.is_nullable() {
+        "nullable"
+    } else {
+        "non-null"
+    };
+    let type_label = dtype_label(&dtype);
+    let indent = path.len() as f64 * 1.5;
 
     rsx! {
-        div { class: "relative flex flex-col max-w-7/12 bg-clip-border",
-            table { class: "table-auto w-full min-w-max text-left border-collapse",
-                thead { class: "bg-neutral-700 border-b border-1 border-zinc-50/10",
-                    tr {
-                        th { class: "p-4",
-                            p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
-                                "Field Name"
-                            }
-                        }
-                        th { class: "p-4",
-                            p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
-                                "Type"
-                            }
-                        }
+        div {
+            class: "flex flex-row items-center gap-x-2 py-0.5 hover:bg-neutral-800/75",
+            style: "padding-left: {indent}rem",
+
+            if has_children {
+                span {
+                    class: "cursor-pointer w-4",
+                    onclick: move |_| {
+                        expanded.toggle();
+                    },
+                    if expanded() {
+                        "▼"
+                    } else {
+                        "►"
                     }
                 }
+            } else {
+                span { class: "w-4" }
+            }
 
-                tbody {
-                    for (field_name , field_type) in names_and_types {
-                        tr { class: "font-normal hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
-                            td { class: "p-1",
-                                p { class: "block font-sans font-bold text-sm antialiased leading-normal",
-                                    "{field_name}"
-                                }
-                            }
-                            td { class: "p-1",
-                                p { class: "block font-mono text-sm antialiased leading-normal",
-                                    "{field_type}"
-                                }
+            if let Some(name) = name {
+                span {
+                    class: "font-sans font-bold",
+                    class: if navigable { "cursor-pointer hover:text-sky-500" },
+                    onclick: {
+                        let path = path.clone();
+                        move |_| {
+                            if navigable {
+                                history_stack.write().descend(&path);
                             }
                         }
-                    }
+                    },
+                    "{name}"
+                }
+            }
+
+            span { "{type_label}" }
+
+            span {
+                class: "text-xs italic",
+                class: if dtype.is_nullable() { "text-amber-400/70" } else { "text-slate-300/30" },
+                "{nullability}"
+            }
+        }
+
+        if has_children && expanded() {
+            for (child_name , child_dtype) in children {
+                DTypeNode {
+                    key: "{child_name}",
+                    name: Some(child_name.clone()),
+                    dtype: child_dtype,
+                    array: array
+                        .as_ref()
+                        .and_then(|array| find_child(array, &child_name))
+                        .map(|child| SharedPtr(Arc::new(child))),
+                    path: path.iter().cloned().chain([child_name]).collect::<Vec<_>>(),
+                    history_stack,
                 }
             }
         }
     }
 }
+
+/// Short label for a DType. Nested types are summarized, since their children are shown below.
+pub fn dtype_label(dtype: &DType) -> String {
+    match dtype {
+        DType::Struct(st, _) => format!("struct ({} fields)", st.names().len()),
+        DType::List(..) => "list".to_string(),
+        DType::Extension(ext) => format!("extension<{}>", ext.id()),
+        other => other.to_string(),
+    }
+}
+
+/// The nested DTypes, keyed by the name of the child array each one is stored in.
+pub fn nested_dtypes(dtype: &DType) -> Vec<(String, DType)> {
+    match dtype {
+        DType::Struct(st, _) => st
+            .names()
+            .iter()
+            .zip(st.dtypes().iter())
+            .map(|(name, dtype)| (name.to_string(), dtype.clone()))
+            .collect(),
+        DType::List(element_dtype, _) => {
+            vec![("elements".to_string(), element_dtype.as_ref().clone())]
+        }
+        DType::Extension(ext) => vec![("storage".to_string(), ext.storage_dtype().clone())],
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,125 @@
+use dioxus::prelude::*;
+
+use crate::{
+    components::{diagnostics::DiagnosticsView, Heading},
+    error::ReadError,
+    file_util::{read_remote_file, DownloadProgress, ReaderConfig},
+    HistoryStack,
+};
+
+/// An error from opening a file, with actions to recover from it.
+#[component]
+pub fn FileErrorView(
+    file_name: Signal<String>,
+    source: Signal<Option<String>>,
+    mut read_error: Signal<Option<ReadError>>,
+    download: Signal<Option<DownloadProgress>>,
+    mut history_stack: Signal<HistoryStack>,
+    error: ReadError,
+) -> Element {
+    let partial = error.partial.clone();
+    let contents = error.contents.clone();
+    let mut show_diagnostics = use_signal(|| false);
+    let config = use_context::<ReaderConfig>();
+
+    rsx! {
+        ReadErrorDetails { error }
+
+        div { class: "flex flex-row gap-x-6 py-4",
+            a {
+                class: "cursor-pointer text-sky-500 text-bold",
+                onclick: move |_| {
+                    read_error.set(None);
+                    history_stack.write().clear();
+                },
+                "Open another file"
+            }
+
+            if let Some(file_url) = source() {
+                a {
+                    class: "cursor-pointer text-sky-500 text-bold",
+                    onclick: move |_| {
+                        let file_url = file_url.clone();
+                        let config = config.clone();
+                        async move {
+                            read_remote_file(
+                                    file_name(),
+                                    file_url,
+                                    config,
+                                    read_error,
+                                    history_stack,
+                                    download,
+                                )
+                                .await;
+                        }
+                    },
+                    "Retry"
+                }
+            }
+
+            if let Some(partial) = partial {
+                a {
+                    class: "cursor-pointer text-sky-500 text-bold",
+                    onclick: move |_| {
+                        read_error.set(None);
+                        let mut stack = history_stack.write();
+                        stack.clear();
+                        stack.push(format!("{} (partial)", file_name()), (*partial).clone());
+                    },
+                    "Show partial results"
+                }
+            }
+
+            if contents.is_some() {
+                a {
+                    class: "cursor-pointer text-sky-500 text-bold",
+                    onclick: move |_| show_diagnostics.toggle(),
+                    if show_diagnostics() {
+                        "Hide diagnostics"
+                    } else {
+                        "Run diagnostics"
+                    }
+                }
+            }
+        }
+
+        if let Some(contents) = contents.filter(|_| show_diagnostics()) {
+            DiagnosticsView { contents }
+        }
+    }
+}
+
+/// Which step of reading failed, the error chain and the backtrace, if one was captured.
+#[component]
+pub fn ReadErrorDetails(error: ReadError) -> Element {
+    rsx! {
+        Heading { text: "Error" }
+
+        div { class: "w-full flex flex-col gap-y-4 p-10 rounded-md border border-red-700",
+            p { class: "font-sans font-bold text-red-700", "{error.stage} failed" }
+            p { class: "font-mono text-red-700 whitespace-pre-wrap", "{error.message}" }
+
+            if let Some(context) = &error.context {
+                p { class: "font-mono text-sm", "{context}" }
+            }
+
+            if !error.chain.is_empty() {
+                div {
+                    p { class: "font-sans text-sm opacity-70", "Caused by:" }
+                    ol { class: "list-decimal pl-8",
+                        for cause in error.chain.iter() {
+                            li { class: "font-mono text-sm whitespace-pre-wrap", "{cause}" }
+                        }
+                    }
+                }
+            }
+
+            if let Some(backtrace) = &error.backtrace {
+                details {
+                    summary { class: "cursor-pointer font-sans text-sm opacity-70", "Backtrace" }
+                    pre { class: "font-mono text-xs overflow-x-auto", "{backtrace}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::future::Future;
+
+use dioxus::prelude::*;
+use vortex::{error::VortexResult, ArrayData};
+
+use crate::{
+    components::{array_info::EncodingRegistry, yield_to_ui},
+    file_util::save_file,
+    html_report::html_report,
+    report::Report,
+    HistoryStack,
+};
+
+/// Renders a description of a whole file, given its name and root array.
+type Exporter = fn(&str, &ArrayData, &EncodingRegistry) -> VortexResult<String>;
+
+/// Actions for saving descriptions of the open file.
+#[component]
+pub fn ExportActions(history_stack: Signal<HistoryStack>) -> Element {
+    let error = use_signal(|| None::<String>);
+    let exporting = use_signal(|| false);
+
+    rsx! {
+        div { class: "flex flex-col gap-y-1 py-2 border-b border-gray-100/10",
+            p { class: "text-lg font-sans text-white", "Export" }
+            a {
+                class: "cursor-pointer text-sky-500 text-sm",
+                title: "JSON description of every node of the file",
+                onclick: move |_| {
+                    export(
+                        history_stack,
+                        error,
+                        exporting,
+                        "report.json",
+                        "application/json",
+                        |name, root, registry| Report::try_new(name, root, registry)?.to_json(),
+                    )
+                },
+                "Download report"
+            }
+            a {
+                class: "cursor-pointer text-sky-500 text-sm",
+                title: "Summary, schema, columns and statistics as a single HTML page",
+                onclick: move |_| {
+                    export(history_stack, error, exporting, "report.html", "text/html", html_report)
+                },
+                "Download HTML report"
+            }
+            if exporting() {
+                p { class: "font-sans text-sm italic", "Exporting…" }
+            }
+            if let Some(error) = error() {
+                p { class: "font-mono text-xs text-red-700 whitespace-pre-wrap", "{error}" }
+            }
+        }
+    }
+}
+
+/// Save the description of the open file as e.g. `data.report.json` for `data.vortex`.
+///
+/// Reports visit every node and profile every column, which blocks the UI while they are built.
+/// The task yields first, so the "Exporting…" note is drawn before building starts.
+fn export(
+    history_stack: Signal<HistoryStack>,
+    mut error: Signal<Option<String>>,
+    mut exporting: Signal<bool>,
+    extension: &'static str,
+    mime_type: &'static str,
+    exporter: Exporter,
+) -> impl Future<Output = ()> {
+    let root = history_stack.read().root().cloned();
+    let registry = consume_context::<EncodingRegistry>();
+    if root.is_some() {
+        exporting.set(true);
+    }
+
+    async move {
+        let Some(root) = root else {
+            return;
+        };
+        yield_to_ui().await;
+        let result = exporter(&root.name, &root.array, &registry);
+        exporting.set(false);
+        match result {
+            Ok(contents) => {
+                error.set(None);
+                let stem = root.name.strip_suffix(".vortex").unwrap_or(&root.name);
+                save_file(&format!("{stem}.{extension}"), contents, mime_type).await;
+            }
+            Err(err) => error.set(Some(err.to_string())),
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use dioxus::prelude::*;
+use vortex::ArrayData;
+
+use crate::{
+    components::Heading,
+    file_util::save_file,
+    graph::{graph_nodes, layout_rows, to_dot, to_mermaid, GraphNode},
+    HistoryStack, SharedPtr,
+};
+
+/// Larger trees are only exported, drawing them would make the page sluggish.
+const MAX_PREVIEW_NODES: usize = 500;
+
+const NODE_WIDTH: f64 = 200.0;
+const NODE_HEIGHT: f64 = 52.0;
+const COLUMN_WIDTH: f64 = 260.0;
+const ROW_HEIGHT: f64 = 64.0;
+const PADDING: f64 = 8.0;
+/// Longest name or encoding that fits on a node, in characters.
+const LABEL_CHARS: usize = 26;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GraphFormat {
+    Preview,
+    Dot,
+    Mermaid,
+}
+
+/// The tree under the current node as a diagram, with DOT and Mermaid exports.
+#[component]
+pub fn TreeGraph(
+    name: String,
+    array: SharedPtr<ArrayData>,
+    history_stack: Signal<HistoryStack>,
+) -> Element {
+    let mut format = use_signal(|| GraphFormat::Preview);
+
+    let nodes = graph_nodes(&name, &array);
+    let (text, extension, mime_type) = match format() {
+        GraphFormat::Preview => (String::new(), "", ""),
+        GraphFormat::Dot => (to_dot(&nodes), "dot", "text/vnd.graphviz"),
+        GraphFormat::Mermaid => (to_mermaid(&nodes), "mmd", "text/plain"),
+    };
+    let download_name = format!("{name}.{extension}");
+    let download_text = text.clone();
+
+    rsx! {
+        Heading { text: "Encoding Graph" }
+
+        div { class: "flex flex-row items-center gap-x-4 pb-2",
+            for (option , label) in [
+                (GraphFormat::Preview, "Preview"),
+                (GraphFormat::Dot, "DOT"),
+                (GraphFormat::Mermaid, "Mermaid"),
+            ]
+            {
+                a {
+                    class: "cursor-pointer font-sans text-sm",
+                    class: if format() == option { "text-sky-500 underline" } else { "hover:text-sky-500" },
+                    onclick: move |_| format.set(option),
+                    "{label}"
+                }
+            }
+
+            if format() != GraphFormat::Preview {
+                a {
+                    class: "ml-auto cursor-pointer text-sky-500 text-sm",
+                    onclick: move |_| {
+                        let (name, text) = (download_name.clone(), download_text.clone());
+                        async move { save_file(&name, text, mime_type).await }
+                    },
+                    "Download {download_name}"
+                }
+            }
+        }
+
+        if format() == GraphFormat::Preview {
+            GraphPreview { nodes, history_stack }
+        } else {
+            GraphText { text }
+        }
+    }
+}
+
+#[component]
+fn GraphText(text: String) -> Element {
+    rsx! {
+        pre { class: "max-h-96 overflow-auto p-2 rounded bg-neutral-800 font-mono text-xs select-all",
+            "{text}"
+        }
+    }
+}
+
+/// Left to right drawing of the tree. Clicking a node opens it.
+#[component]
+fn GraphPreview(nodes: Vec<GraphNode>, history_stack: Signal<HistoryStack>) -> Element {
+    if nodes.len() > MAX_PREVIEW_NODES {
+        return rsx! {
+            p { class: "font-sans text-sm",
+                "The tree has {nodes.len()} nodes, too many to preview. Download the DOT or Mermaid export instead."
+            }
+        };
+    }
+
+    let rows = layout_rows(&nodes);
+    let columns = nodes.iter().map(|node| node.path.len()).max().unwrap_or(0) + 1;
+    let row_count = rows.iter().fold(0.0_f64, |max, row| max.max(*row)) + 1.0;
+    let width = (columns - 1) as f64 * COLUMN_WIDTH + NODE_WIDTH + 2.0 * PADDING;
+    let height = row_count * ROW_HEIGHT + 2.0 * PADDING;
+
+    // Top left corner of every node.
+    let position = |idx: usize| {
+        (
+            PADDING + nodes[idx].path.len() as f64 * COLUMN_WIDTH,
+            PADDING + rows[idx] * ROW_HEIGHT,
+        )
+    };
+    let edges: Vec<String> = nodes
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, node)| {
+            let (parent_x, parent_y) = position(node.parent?);
+            let (x, y) = position(idx);
+            let (x1, y1) = (parent_x + NODE_WIDTH, parent_y + NODE_HEIGHT / 2.0);
+            let (x2, y2) = (x, y + NODE_HEIGHT / 2.0);
+            let mid = (x1 + x2) / 2.0;
+            Some(format!("M {x1} {y1} C {mid} {y1}, {mid} {y2}, {x2} {y2}"))
+        })
+        .collect();
+    let boxes: Vec<(f64, f64, GraphNode)> = nodes
+        .iter()
+        .enumerate()
+        .map(|(idx, node)| {
+            let (x, y) = position(idx);
+            (x, y, node.clone())
+        })
+        .collect();
+
+    rsx! {
+        div { class: "max-h-[36rem] overflow-auto rounded bg-neutral-900",
+            svg {
+                width: "{width}",
+                height: "{height}",
+                view_box: "0 0 {width} {height}",
+
+                for d in edges {
+                    path {
+                        d,
+                        fill: "none",
+                        stroke: "#71717a",
+                        stroke_width: "1.5",
+                    }
+                }
+
+                for (x , y , node) in boxes {
+                    GraphNodeBox { x, y, node, history_stack }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn GraphNodeBox(
+    x: f64,
+    y: f64,
+    node: GraphNode,
+    mut history_stack: Signal<HistoryStack>,
+) -> Element {
+    let name = truncate(&node.name);
+    let encoding = truncate(&node.encoding);
+    let size = node.size();
+    let stroke = if node.parent.is_none() {
+        "#0ea5e9"
+    } else {
+        "#52525b"
+    };
+
+    rsx! {
+        g {
+            class: "cursor-pointer",
+            onclick: move |_| {
+                let mut path = history_stack.read().path();
+                path.extend(node.path.iter().cloned());
+                history_stack.write().goto_path(&path);
+            },
+            rect {
+                x: "{x}",
+                y: "{y}",
+                width: "{NODE_WIDTH}",
+                height: "{NODE_HEIGHT}",
+                rx: "6",
+                fill: "#262626",
+                stroke,
+            }
+            text {
+                x: "{x + 8.0}",
+                y: "{y + 15.0}",
+                fill: "white",
+                font_family: "sans-serif",
+                font_size: "12",
+                font_weight: "bold",
+                "{name}"
+            }
+            text {
+                x: "{x + 8.0}",
+                y: "{y + 30.0}",
+                fill: "#d4d4d8",
+                font_family: "monospace",
+                font_size: "11",
+                "{encoding}"
+            }
+            text {
+                x: "{x + 8.0}",
+                y: "{y + 45.0}",
+                fill: "#a1a1aa",
+                font_family: "monospace",
+                font_size: "11",
+                "{size}"
+            }
+        }
+    }
+}
+
+fn truncate(label: &str) -> String {
+    if label.chars().count() <= LABEL_CHARS {
+        label.to_string()
+    } else {
+        let mut truncated: String = label.chars().take(LABEL_CHARS - 1).collect();
+        truncated.push('…');
+        truncated
+    }
+}
@@ -0,0 +1,51 @@
+use std::ops::Range;
+
+use bytes::Bytes;
+use dioxus::prelude::*;
+
+/// Bytes shown per row of a hex dump.
+pub const ROW_WIDTH: usize = 16;
+
+/// Hex dump of a window of bytes, with their offsets and printable characters.
+///
+/// `offset` is the position of the first byte of `bytes`, and `highlight` is a range of positions
+/// to emphasize, both relative to the same origin, e.g. the start of the file.
+#[component]
+pub fn HexDump(bytes: Bytes, offset: usize, highlight: Option<Range<usize>>) -> Element {
+    let is_highlighted =
+        move |pos: usize| highlight.as_ref().is_some_and(|range| range.contains(&pos));
+
+    rsx! {
+        div { class: "overflow-x-auto font-mono text-xs leading-5",
+            for (row , chunk) in bytes.chunks(ROW_WIDTH).enumerate() {
+                div { class: "flex flex-row gap-x-4 whitespace-pre",
+                    span { class: "opacity-50", "{offset + row * ROW_WIDTH:08x}" }
+                    span {
+                        for (col , byte) in chunk.iter().enumerate() {
+                            span { class: if is_highlighted(offset + row * ROW_WIDTH + col) { "bg-red-700/60" },
+                                "{byte:02x} "
+                            }
+                        }
+                        // Pad short final rows so the characters stay aligned.
+                        {"   ".repeat(ROW_WIDTH - chunk.len())}
+                    }
+                    span {
+                        for (col , byte) in chunk.iter().enumerate() {
+                            span { class: if is_highlighted(offset + row * ROW_WIDTH + col) { "bg-red-700/60" },
+                                "{printable(*byte)}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn printable(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        byte as char
+    } else {
+        '.'
+    }
+}
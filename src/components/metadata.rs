@@ -0,0 +1,56 @@
+use bytes::Bytes;
+use dioxus::prelude::*;
+use vortex::ArrayData;
+
+use crate::{
+    components::{hex::HexDump, Heading},
+    summary::encoding_label,
+    SharedPtr,
+};
+
+/// The encoding-specific metadata of an array, both decoded and as the raw serialized bytes.
+///
+/// Works for every encoding, including those without a dedicated encoding info panel.
+#[component]
+pub fn MetadataPanel(array: SharedPtr<ArrayData>) -> Element {
+    let mut show_raw = use_signal(|| false);
+
+    let encoding = encoding_label(&array);
+    let decoded = format!("{:#?}", array.array_metadata());
+    let raw = array.metadata_bytes().map(Bytes::copy_from_slice);
+
+    rsx! {
+        Heading { text: "Metadata" }
+
+        p { class: "pb-2 font-sans text-sm opacity-70", "Metadata of {encoding}" }
+        pre { class: "p-4 rounded bg-neutral-800 font-mono text-sm overflow-x-auto", "{decoded}" }
+
+        {
+            match raw {
+                Some(raw) if !raw.is_empty() => rsx! {
+                    a {
+                        class: "text-lg flex flex-row cursor-pointer gap-x-1 pt-4",
+                        onclick: move |_| {
+                            show_raw.toggle();
+                        },
+                        if show_raw() {
+                            span { "▼  " }
+                        } else {
+                            span { "►  " }
+                        }
+                        "Raw metadata ({raw.len()} bytes)"
+                    }
+
+                    if show_raw() {
+                        HexDump { bytes: raw.clone(), offset: 0, highlight: None }
+                    }
+                },
+                _ => rsx! {
+                    p { class: "pt-4 font-regular font-sans text-sm italic text-slate-300/30",
+                        "No serialized metadata"
+                    }
+                },
+            }
+        }
+    }
+}
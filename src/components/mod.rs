@@ -1,13 +1,34 @@
 use dioxus::prelude::*;
 
-use crate::LOGO;
+use crate::{HistoryStack, LOGO};
+use array::ArrayView;
+use export::ExportActions;
+use search::TreeSearch;
+use tree::TreeNavigator;
 
 pub mod array;
 pub mod array_info;
 pub mod breadcrumb;
+pub mod buffers;
+pub mod compare;
+pub mod diagnostics;
 pub mod dtype;
+pub mod error;
+pub mod export;
+pub mod graph;
+pub mod hex;
+pub mod metadata;
+pub mod profile;
+#[cfg(feature = "desktop")]
+pub mod recent_files;
+#[cfg(feature = "desktop")]
+pub mod reload;
 pub mod sample_files;
+pub mod search;
 pub mod stats;
+pub mod tabs;
+pub mod tree;
+pub mod validity;
 
 #[component]
 pub fn AppHeader() -> Element {
@@ -19,6 +40,58 @@ pub fn AppHeader() -> Element {
     }
 }
 
+/// The top-level views available once a file is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewMode {
+    /// Walk the array tree one node at a time.
+    Explore,
+    /// Profile every column of the file at once.
+    Profile,
+    /// Compare the file against a second file.
+    Compare,
+}
+
+#[component]
+pub fn ViewModeTabs(mut view_mode: Signal<ViewMode>) -> Element {
+    rsx! {
+        div { class: "flex flex-row gap-x-4 border-b border-gray-100/10",
+            for (mode , label) in [
+                (ViewMode::Explore, "Explore"),
+                (ViewMode::Profile, "Profile"),
+                (ViewMode::Compare, "Compare"),
+            ]
+            {
+                a {
+                    class: "px-2 py-1 cursor-pointer font-sans",
+                    class: if view_mode() == mode { "border-b-2 border-sky-500 text-sky-500" } else { "hover:text-sky-500" },
+                    onclick: move |_| {
+                        view_mode.set(mode);
+                    },
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+/// Walk the array tree, with the tree navigator and search in a sidebar next to the current node.
+#[component]
+pub fn ExploreView(file_name: String, history_stack: Signal<HistoryStack>) -> Element {
+    rsx! {
+        div { class: "flex flex-row gap-x-6",
+            div { class: "flex flex-col",
+                ExportActions { history_stack }
+                TreeSearch { history_stack }
+                TreeNavigator { history_stack }
+            }
+
+            div { class: "flex-1 min-w-0",
+                ArrayView { file_name, history_stack }
+            }
+        }
+    }
+}
+
 #[component]
 pub fn Heading(text: String) -> Element {
     rsx! {
@@ -35,3 +108,13 @@ pub fn ErrorMessage(error: String) -> Element {
         }
     }
 }
+
+/// Hand control back to the webview, so it can render and handle events, before continuing with
+/// long-running work.
+///
+/// Tasks run on the UI thread, so a task that never awaits freezes the app just like a click
+/// handler would. Waiting on a timeout in the webview returns control to its event loop until the
+/// next turn.
+pub async fn yield_to_ui() {
+    let _ = document::eval("await new Promise(resolve => setTimeout(resolve, 0));").await;
+}
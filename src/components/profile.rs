@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+
+use dioxus::prelude::*;
+use vortex::ArrayData;
+
+use crate::{
+    components::{yield_to_ui, ErrorMessage, Heading},
+    profile::{profile_column, struct_columns, ColumnProfile},
+    SharedPtr,
+};
+
+/// Columns of the profile table that can be sorted on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortKey {
+    Name,
+    Rows,
+    NullPct,
+    Distinct,
+    Mean,
+    StdDev,
+    AvgLength,
+}
+
+impl SortKey {
+    fn compare(&self, a: &ColumnProfile, b: &ColumnProfile) -> Ordering {
+        // Columns without a value sort before those with one.
+        fn by<T: PartialOrd>(a: Option<T>, b: Option<T>) -> Ordering {
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        }
+
+        match self {
+            SortKey::Name => a.name.cmp(&b.name),
+            SortKey::Rows => a.row_count.cmp(&b.row_count),
+            SortKey::NullPct => by(Some(a.null_pct()), Some(b.null_pct())),
+            SortKey::Distinct => a.distinct_count.cmp(&b.distinct_count),
+            SortKey::Mean => by(a.numeric.map(|n| n.mean), b.numeric.map(|n| n.mean)),
+            SortKey::StdDev => by(a.numeric.map(|n| n.stddev), b.numeric.map(|n| n.stddev)),
+            SortKey::AvgLength => by(a.lengths.map(|l| l.mean), b.lengths.map(|l| l.mean)),
+        }
+    }
+}
+
+/// Profile report for every top-level field of the file.
+#[component]
+pub fn ProfileView(array: SharedPtr<ArrayData>) -> Element {
+    // Profiling scans every value, so it runs in a task that hands control back to the webview
+    // between columns, and is only redone when a different file is opened.
+    let profiles = use_resource(use_reactive!(|array| async move {
+        yield_to_ui().await;
+        let columns = struct_columns(&array).map_err(|err| err.to_string())?;
+        let mut profiles = Vec::with_capacity(columns.len());
+        for (name, column) in columns {
+            yield_to_ui().await;
+            profiles.push(profile_column(name, &column).map_err(|err| err.to_string())?);
+        }
+        Ok::<_, String>(profiles)
+    }));
+
+    let profiles = match profiles() {
+        None => {
+            return rsx! {
+                Heading { text: "Profile" }
+                p { class: "font-sans text-sm italic", "Profiling columns…" }
+            }
+        }
+        Some(Ok(profiles)) => profiles,
+        Some(Err(error)) => {
+            return rsx! {
+                ErrorMessage { error }
+            }
+        }
+    };
+
+    rsx! {
+        Heading { text: "Profile" }
+
+        ProfileTable { profiles }
+    }
+}
+
+#[component]
+fn ProfileTable(profiles: Vec<ColumnProfile>) -> Element {
+    let sort_key = use_signal(|| SortKey::Name);
+    let descending = use_signal(|| false);
+
+    let mut profiles = profiles;
+    profiles.sort_by(|a, b| sort_key().compare(a, b));
+    if descending() {
+        profiles.reverse();
+    }
+
+    rsx! {
+        div { class: "relative flex flex-col w-full overflow-x-auto bg-clip-border",
+            table { class: "table-auto w-full min-w-max text-left border-collapse",
+                thead { class: "bg-neutral-700 border-b border-1 border-zinc-50/10",
+                    tr {
+                        SortableHeader { label: "Column", column: SortKey::Name, sort_key, descending }
+                        SortableHeader { label: "Rows", column: SortKey::Rows, sort_key, descending }
+                        SortableHeader { label: "Null %", column: SortKey::NullPct, sort_key, descending }
+                        SortableHeader { label: "Distinct", column: SortKey::Distinct, sort_key, descending }
+                        PlainHeader { label: "Min" }
+                        PlainHeader { label: "Max" }
+                        PlainHeader { label: "Top Values" }
+                        SortableHeader { label: "Mean", column: SortKey::Mean, sort_key, descending }
+                        SortableHeader { label: "Std Dev", column: SortKey::StdDev, sort_key, descending }
+                        SortableHeader { label: "Length (min / avg / max)", column: SortKey::AvgLength, sort_key, descending }
+                    }
+                }
+
+                tbody {
+                    for profile in profiles {
+                        ProfileRow { profile }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ProfileRow(profile: ColumnProfile) -> Element {
+    let null_pct = format!("{:.2}", profile.null_pct());
+    let distinct = profile
+        .distinct_count
+        .map(|count| format!("~{count}"))
+        .unwrap_or_default();
+    let min = profile.min.clone().unwrap_or_default();
+    let max = profile.max.clone().unwrap_or_default();
+    let mean = profile
+        .numeric
+        .map(|numeric| format!("{:.4}", numeric.mean))
+        .unwrap_or_default();
+    let stddev = profile
+        .numeric
+        .map(|numeric| format!("{:.4}", numeric.stddev))
+        .unwrap_or_default();
+    let lengths = profile
+        .lengths
+        .map(|lengths| format!("{} / {:.1} / {}", lengths.min, lengths.mean, lengths.max))
+        .unwrap_or_default();
+
+    rsx! {
+        tr { class: "font-normal align-top hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
+            td { class: "p-2",
+                p { class: "block font-sans font-bold text-sm antialiased leading-normal",
+                    "{profile.name}"
+                }
+                p { class: "block font-mono text-xs antialiased leading-normal opacity-70",
+                    "{profile.dtype}"
+                }
+            }
+            ProfileCell { value: profile.row_count.to_string() }
+            ProfileCell { value: null_pct }
+            ProfileCell { value: distinct }
+            ProfileCell { value: min }
+            ProfileCell { value: max }
+            td { class: "p-2",
+                for (value , count) in profile.top_values.iter() {
+                    p { class: "block font-mono text-sm antialiased leading-normal",
+                        "{value} ({profile.format_top_count(*count)})"
+                    }
+                }
+            }
+            ProfileCell { value: mean }
+            ProfileCell { value: stddev }
+            ProfileCell { value: lengths }
+        }
+    }
+}
+
+#[component]
+fn ProfileCell(value: String) -> Element {
+    rsx! {
+        td { class: "p-2",
+            p { class: "block font-mono text-sm antialiased leading-normal", "{value}" }
+        }
+    }
+}
+
+#[component]
+fn PlainHeader(label: String) -> Element {
+    rsx! {
+        th { class: "p-4",
+            p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
+                "{label}"
+            }
+        }
+    }
+}
+
+/// Table header that sorts on its column when clicked, toggling the direction on repeat clicks.
+#[component]
+fn SortableHeader(
+    label: String,
+    column: SortKey,
+    mut sort_key: Signal<SortKey>,
+    mut descending: Signal<bool>,
+) -> Element {
+    let active = sort_key() == column;
+    let arrow = match (active, descending()) {
+        (false, _) => "",
+        (true, false) => " ▲",
+        (true, true) => " ▼",
+    };
+
+    rsx! {
+        th {
+            class: "p-4 cursor-pointer hover:text-sky-500",
+            onclick: move |_| {
+                if sort_key() == column {
+                    descending.toggle();
+                } else {
+                    sort_key.set(column);
+                    descending.set(false);
+                }
+            },
+            p { class: "block font-sans text-sm antialiased font-normal leading-none opacity-70",
+                "{label}{arrow}"
+            }
+        }
+    }
+}
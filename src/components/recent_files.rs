@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use dioxus::prelude::*;
+
+use crate::{
+    desktop::{forget_file, open_paths, pick_files, recent_files},
+    file_util::ReaderConfig,
+    OpenTabs,
+};
+
+/// Button for the native open dialog, followed by the recently opened files.
+#[component]
+pub fn NativeFilePicker() -> Element {
+    let open_tabs = use_context::<OpenTabs>();
+    let recent = use_signal(recent_files);
+
+    rsx! {
+        a {
+            class: "cursor-pointer text-sky-500 text-bold",
+            onclick: move |_| {
+                let config = consume_context::<ReaderConfig>();
+                async move {
+                    let paths = pick_files().await;
+                    if !paths.is_empty() {
+                        open_paths(open_tabs, paths, config);
+                    }
+                }
+            },
+            "Open files…"
+        }
+
+        if !recent.read().is_empty() {
+            div { class: "py-4",
+                p { "Recently opened:" }
+                ul {
+                    for path in recent() {
+                        RecentFile { path, open_tabs, recent }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn RecentFile(path: PathBuf, open_tabs: OpenTabs, mut recent: Signal<Vec<PathBuf>>) -> Element {
+    let display = path.display().to_string();
+    // Files can be moved or deleted between sessions.
+    let exists = path.exists();
+    let forget_path = path.clone();
+
+    rsx! {
+        li { class: "flex flex-row items-center gap-x-3",
+            a {
+                class: "font-mono text-sm",
+                class: if exists { "cursor-pointer text-sky-500" } else { "text-slate-300/30 line-through" },
+                title: if !exists { "The file no longer exists" },
+                onclick: move |_| {
+                    if exists {
+                        open_paths(open_tabs, vec![path.clone()], consume_context::<ReaderConfig>());
+                    }
+                },
+                "{display}"
+            }
+            a {
+                class: "cursor-pointer text-xs opacity-70 hover:opacity-100 hover:text-red-500",
+                title: "Remove from the list",
+                onclick: move |_| {
+                    forget_file(&forget_path);
+                    recent.set(recent_files());
+                },
+                "✕"
+            }
+        }
+    }
+}
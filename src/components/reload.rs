@@ -0,0 +1,139 @@
+use dioxus::logger::tracing::warn;
+use dioxus::prelude::*;
+use futures::StreamExt;
+
+use crate::{
+    desktop::{reload_file, watch_file, Reload},
+    file_util::ReaderConfig,
+    summary::{NodeChange, NodeShape},
+    Tab,
+};
+
+/// Most changes listed after a reload, rewriting a file can touch every node of it.
+const MAX_CHANGES: usize = 50;
+
+/// Watch the file open in a tab, reloading it whenever it changes on disk.
+///
+/// Renders what changed in the last reload while the tab is active.
+#[component]
+pub fn FileWatcher(tab: Tab, active: bool) -> Element {
+    let config = use_context::<ReaderConfig>();
+
+    // Restarted whenever a different file is opened in the tab. Dropping the previous future stops
+    // watching the previous file.
+    use_resource(move || {
+        let path = tab.path.read().clone();
+        let config = config.clone();
+        async move {
+            let Some(path) = path else {
+                return;
+            };
+            let (_debouncer, mut changes) = match watch_file(&path) {
+                Ok(watch) => watch,
+                Err(err) => {
+                    warn!("could not watch {}: {err}", path.display());
+                    return;
+                }
+            };
+            while changes.next().await.is_some() {
+                reload_file(tab, path.clone(), config.clone()).await;
+            }
+        }
+    });
+
+    let reload = tab.reload;
+    match reload() {
+        Some(reload) if active => rsx! {
+            ReloadNotice { reload: reload.clone(), file_name: (tab.file_name)(), tab }
+        },
+        _ => rsx! {},
+    }
+}
+
+#[component]
+fn ReloadNotice(reload: Reload, file_name: String, tab: Tab) -> Element {
+    let mut notice = tab.reload;
+    let (title, detail) = match (&reload.error, reload.restored) {
+        (Some(error), _) => (
+            format!("Could not reload {file_name}, still showing the previous version."),
+            Some(error.clone()),
+        ),
+        (None, true) => (
+            format!("Reloaded {file_name} after it changed on disk."),
+            None,
+        ),
+        (None, false) => (
+            format!("Reloaded {file_name} after it changed on disk."),
+            Some(
+                "The node that was open no longer exists, showing its closest ancestor."
+                    .to_string(),
+            ),
+        ),
+    };
+    let hidden = reload.changes.len().saturating_sub(MAX_CHANGES);
+
+    rsx! {
+        div {
+            class: "mx-4 mt-2 p-3 rounded border font-sans text-sm",
+            class: if reload.error.is_some() { "border-red-700" } else { "border-sky-500/50" },
+            div { class: "flex flex-row items-center gap-x-3",
+                p { class: "font-bold", "{title}" }
+                a {
+                    class: "ml-auto cursor-pointer text-xs opacity-70 hover:opacity-100 hover:text-red-500",
+                    onclick: move |_| notice.set(None),
+                    "✕"
+                }
+            }
+            if let Some(detail) = detail {
+                p { class: "font-mono text-xs opacity-70 whitespace-pre-wrap", "{detail}" }
+            }
+
+            if reload.error.is_none() {
+                if reload.changes.is_empty() {
+                    p { class: "opacity-70", "No encodings or sizes changed." }
+                } else {
+                    table { class: "table-auto mt-2 text-left border-collapse",
+                        tbody {
+                            for change in reload.changes.iter().take(MAX_CHANGES).cloned() {
+                                ChangeRow { change }
+                            }
+                        }
+                    }
+                    if hidden > 0 {
+                        p { class: "opacity-70", "…and {hidden} more changes." }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ChangeRow(change: NodeChange) -> Element {
+    let path = format!("/{}", change.path.join("/"));
+    let size = |shape: &NodeShape| humansize::format_size(shape.nbytes, humansize::BINARY);
+    let description = match (&change.before, &change.after) {
+        (None, Some(after)) => format!("added, {} ({})", after.encoding, size(after)),
+        (Some(before), None) => format!("removed, was {} ({})", before.encoding, size(before)),
+        (Some(before), Some(after)) if before.encoding != after.encoding => format!(
+            "{} → {}, {} → {}",
+            before.encoding,
+            after.encoding,
+            size(before),
+            size(after)
+        ),
+        (Some(before), Some(after)) => {
+            let delta = after.nbytes as f64 - before.nbytes as f64;
+            let pct = 100.0 * delta / (before.nbytes.max(1) as f64);
+            format!("{} → {} ({pct:+.1}%)", size(before), size(after))
+        }
+        (None, None) => String::new(),
+    };
+
+    rsx! {
+        tr { class: "font-normal hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
+            td { class: "py-1 pr-4 font-mono text-xs", "{path}" }
+            td { class: "py-1 font-mono text-xs", "{description}" }
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use crate::file_util::read_file;
+use crate::error::ReadError;
+use crate::file_util::{read_remote_file, DownloadProgress, ReaderConfig};
 use crate::HistoryStack;
 use dioxus::logger::tracing::info;
 use dioxus::prelude::*;
@@ -22,33 +23,39 @@ const SAMPLE_FILES: [(&str, &str); 2] = [
 enum LoadState {
     Default,
     Loading,
+    Failed,
     Finished,
 }
 
 #[component]
 pub fn SampleFiles(
     mut file_name: Signal<String>,
-    mut read_error: Signal<Option<String>>,
-    mut history_stack: Signal<HistoryStack>,
+    mut source: Signal<Option<String>>,
+    read_error: Signal<Option<ReadError>>,
+    download: Signal<Option<DownloadProgress>>,
+    history_stack: Signal<HistoryStack>,
 ) -> Element {
-    let mut load_state = use_signal(|| LoadState::Loading);
+    let mut load_state = use_signal(|| LoadState::Default);
 
-    let read_sample_file = move |file: String, file_url: String| async move {
-        info!("fetching {file_url}");
-        let contents = reqwest::Client::new()
-            .get(file_url)
-            .send()
-            .await
-            .unwrap()
-            .bytes()
-            .await
-            .unwrap();
-        info!("completed request to server for demo file");
-        *file_name.write() = file.clone();
-        read_file(file, contents, read_error, history_stack).await;
-        *load_state.write() = LoadState::Finished;
+    // Only called from event handlers, where the context can be looked up directly. This keeps the
+    // closure `Copy` so every link can share it.
+    let read_sample_file = move |file: String, file_url: String| {
+        let config = consume_context::<ReaderConfig>();
+        async move {
+            *load_state.write() = LoadState::Loading;
+            *file_name.write() = file.clone();
+            *source.write() = Some(file_url.clone());
+            read_remote_file(file, file_url, config, read_error, history_stack, download).await;
+            *load_state.write() = if read_error.read().is_some() {
+                LoadState::Failed
+            } else {
+                LoadState::Finished
+            };
+        }
     };
 
+    let loading = *load_state.read() == LoadState::Loading || download.read().is_some();
+
     rsx! {
         div { class: "p-4",
             p { "Or, select a sample file below:" }
@@ -56,10 +63,12 @@ pub fn SampleFiles(
                 for (file_name , file_url) in SAMPLE_FILES.iter().cloned() {
                     li {
                         a {
-                            class: "cursor-pointer text-sky-500 text-bold",
-                            class: if *load_state.read() == LoadState::Loading { "" },
+                            class: "text-bold",
+                            class: if loading { "text-slate-300/30 cursor-wait" } else { "cursor-pointer text-sky-500" },
                             onclick: move |_| async move {
-                                *load_state.write() = LoadState::Loading;
+                                if loading {
+                                    return;
+                                }
                                 info!("clicked on {file_url}");
                                 read_sample_file(file_name.to_string(), file_url.to_string()).await;
                             },
@@ -68,6 +77,43 @@ pub fn SampleFiles(
                     }
                 }
             }
+
+            if let Some(progress) = download() {
+                DownloadIndicator { file_name: file_name(), progress }
+            }
+        }
+    }
+}
+
+/// Spinner and progress bar for a running download.
+#[component]
+pub fn DownloadIndicator(file_name: String, progress: DownloadProgress) -> Element {
+    let received = humansize::format_size(progress.received, humansize::BINARY);
+    let status = match progress.total {
+        Some(total) => format!(
+            "{received} of {}",
+            humansize::format_size(total, humansize::BINARY)
+        ),
+        None => received,
+    };
+
+    rsx! {
+        div { class: "flex flex-col gap-y-2 my-4 max-w-md",
+            div { class: "flex flex-row items-center gap-x-3",
+                div { class: "w-4 h-4 rounded-full border-2 border-sky-500 border-t-transparent animate-spin" }
+                p { class: "font-sans text-sm", "Downloading {file_name}… {status}" }
+            }
+            div { class: "w-full h-2 rounded bg-neutral-700 overflow-hidden",
+                if let Some(fraction) = progress.fraction() {
+                    div {
+                        class: "h-full bg-sky-500",
+                        style: "width: {fraction * 100.0}%",
+                    }
+                } else {
+                    // Unknown length, fill the bar with a pulsing placeholder instead.
+                    div { class: "h-full w-full bg-sky-500/50 animate-pulse" }
+                }
+            }
         }
     }
 }
@@ -0,0 +1,96 @@
+use dioxus::prelude::*;
+
+use crate::{
+    search::{search, Query, SearchMatch},
+    HistoryStack,
+};
+
+/// Most results listed at once, very broad queries can match every node of a large file.
+const MAX_RESULTS: usize = 200;
+
+/// Search box querying the whole array tree, listing the paths of matching nodes.
+///
+/// Searching visits every node, so it runs when the query is submitted rather than on every key.
+#[component]
+pub fn TreeSearch(history_stack: Signal<HistoryStack>) -> Element {
+    let mut input = use_signal(String::new);
+    let mut query = use_signal(String::new);
+
+    // Only changes when another file is opened, not on every navigation within the file.
+    let root = use_memo(move || history_stack.read().root().cloned());
+    let results = use_memo(move || -> Result<Vec<SearchMatch>, String> {
+        let query = Query::parse(&query.read())?;
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(root
+            .read()
+            .as_ref()
+            .map(|root| search(&root.name, &root.array, &query))
+            .unwrap_or_default())
+    });
+
+    rsx! {
+        div { class: "flex flex-col gap-y-2 py-2 border-b border-gray-100/10",
+            input {
+                class: "px-2 py-1 rounded bg-neutral-800 font-mono text-sm",
+                r#type: "search",
+                placeholder: "Search, e.g. fsst or nbytes > 1MB, then press Enter",
+                value: "{input}",
+                oninput: move |evt| {
+                    // Clearing the box clears the results straight away.
+                    if evt.value().trim().is_empty() {
+                        query.set(String::new());
+                    }
+                    input.set(evt.value());
+                },
+                onchange: move |evt| query.set(evt.value()),
+            }
+
+            {
+                match results() {
+                    Err(error) => rsx! {
+                        p { class: "font-mono text-xs text-red-700", "{error}" }
+                    },
+                    Ok(matches) if matches.is_empty() && !query.read().trim().is_empty() => rsx! {
+                        p { class: "font-regular font-sans text-sm italic text-slate-300/30", "No matches" }
+                    },
+                    Ok(matches) => rsx! {
+                        if matches.len() > MAX_RESULTS {
+                            p { class: "font-regular font-sans text-sm italic text-slate-300/30",
+                                "Showing {MAX_RESULTS} of {matches.len()} matches"
+                            }
+                        }
+                        div { class: "flex flex-col max-h-96 overflow-y-auto",
+                            for result in matches.into_iter().take(MAX_RESULTS) {
+                                SearchResult { result, history_stack }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SearchResult(result: SearchMatch, mut history_stack: Signal<HistoryStack>) -> Element {
+    let label = if result.path.is_empty() {
+        "/".to_string()
+    } else {
+        result.path.join("/")
+    };
+    let size = humansize::format_size(result.nbytes, humansize::BINARY);
+
+    rsx! {
+        div {
+            class: "flex flex-col py-1 cursor-pointer hover:bg-neutral-800/75 hover:text-sky-500",
+            onclick: move |_| {
+                history_stack.write().goto_path(&result.path);
+            },
+            p { class: "font-mono text-sm break-all", "{label}" }
+            p { class: "font-mono text-xs opacity-70", "{result.encoding} · {result.dtype} · {size}" }
+        }
+    }
+}
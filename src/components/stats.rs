@@ -1,10 +1,12 @@
 use dioxus::prelude::*;
+use vortex::dtype::DType;
 use vortex::stats::StatsSet;
 
 use crate::components::Heading;
+use crate::scalar_fmt::format_stat;
 
 #[component]
-pub fn Statistics(stats: StatsSet) -> Element {
+pub fn Statistics(stats: StatsSet, dtype: DType) -> Element {
     rsx! {
         div {
             Heading { text: "Statistics" }
@@ -12,19 +14,19 @@ pub fn Statistics(stats: StatsSet) -> Element {
             if stats.is_empty() {
                 p { "No stats" }
             } else {
-                StatsTable { stats }
+                StatsTable { stats, dtype }
             }
         }
     }
 }
 
 #[component]
-fn StatsTable(stats: StatsSet) -> Element {
+fn StatsTable(stats: StatsSet, dtype: DType) -> Element {
     rsx! {
         div { class: "relative flex flex-col max-w-7/12 bg-clip-border",
             table { class: "table-auto w-full min-w-max text-left border-collapse",
                 tbody {
-                    for (stat , value) in stats.clone().into_iter().map(|(s, v)| (s, v)) {
+                    for (stat , value) in stats.clone().into_iter().map(|(s, v)| (s, format_stat(&dtype, s, &v))) {
                         tr { class: "font-normal hover:bg-neutral-800/75 border-b border-1 border-zinc-50/10",
                             td { class: "p-2",
                                 p { class: "block font-sans text-sm antialiased leading-normal",
@@ -0,0 +1,58 @@
+use dioxus::prelude::*;
+
+use crate::Tab;
+
+/// Row of tabs, one per open file, plus a button to open another tab.
+#[component]
+pub fn TabBar(mut tabs: Signal<Vec<Tab>>, mut active_tab: Signal<usize>) -> Element {
+    let tab_count = tabs.read().len();
+
+    rsx! {
+        div { class: "flex flex-row items-end gap-x-1 px-3 pt-2 border-b border-gray-100/10 overflow-x-auto",
+            for (idx , tab) in tabs.read().iter().copied().enumerate() {
+                div {
+                    key: "{tab.id}",
+                    class: "flex flex-row items-center gap-x-2 px-3 py-1 rounded-t cursor-pointer",
+                    class: if idx == active_tab() { "bg-neutral-700 text-sky-500" } else { "hover:bg-neutral-800/75" },
+                    onclick: move |_| {
+                        active_tab.set(idx);
+                    },
+
+                    span { class: "font-sans text-sm whitespace-nowrap",
+                        if tab.file_name.read().is_empty() {
+                            "New tab"
+                        } else {
+                            "{tab.file_name}"
+                        }
+                    }
+
+                    // Always keep at least one tab open.
+                    if tab_count > 1 {
+                        span {
+                            class: "text-xs opacity-70 hover:opacity-100 hover:text-red-500",
+                            onclick: move |evt| {
+                                evt.stop_propagation();
+                                let closed = tabs.write().remove(idx);
+                                let active = active_tab();
+                                if active > idx || active == tabs.read().len() {
+                                    active_tab.set(active.saturating_sub(1));
+                                }
+                                closed.release();
+                            },
+                            "✕"
+                        }
+                    }
+                }
+            }
+
+            a {
+                class: "px-3 py-1 cursor-pointer font-sans text-sm hover:text-sky-500",
+                onclick: move |_| {
+                    tabs.write().push(Tab::new());
+                    active_tab.set(tabs.read().len() - 1);
+                },
+                "+"
+            }
+        }
+    }
+}
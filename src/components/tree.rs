@@ -0,0 +1,150 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use dioxus::prelude::*;
+use vortex::ArrayData;
+
+use crate::{
+    summary::{encoding_label, node_children, node_nbytes},
+    HistoryStack, SharedPtr,
+};
+
+/// Collapsible tree of the whole array hierarchy, kept in sync with the history stack.
+///
+/// Clicking a node jumps straight to it, however deep it is.
+#[component]
+pub fn TreeNavigator(history_stack: Signal<HistoryStack>) -> Element {
+    // Paths of the expanded nodes, relative to the root.
+    let mut expanded = use_signal(BTreeSet::<Vec<String>>::new);
+
+    // Expand the ancestors of the current node whenever it changes, so it is always visible.
+    use_effect(move || {
+        let path = history_stack.read().path();
+        let mut expanded = expanded.write();
+        for depth in 0..path.len() {
+            expanded.insert(path[..depth].to_vec());
+        }
+    });
+
+    let Some(root) = history_stack.read().root().cloned() else {
+        return rsx! {};
+    };
+    let current = history_stack.read().path();
+
+    rsx! {
+        div { class: "flex flex-col min-w-64 max-w-sm max-h-screen overflow-auto pr-4 border-r border-gray-100/10",
+            div { class: "flex flex-row items-center gap-x-4 py-2",
+                p { class: "text-lg font-sans text-white", "Tree" }
+                a {
+                    class: "ml-auto cursor-pointer text-sky-500 text-sm",
+                    onclick: move |_| {
+                        let mut paths = BTreeSet::new();
+                        if let Some(root) = history_stack.read().root() {
+                            collect_paths(&root.array, &mut Vec::new(), &mut paths);
+                        }
+                        expanded.set(paths);
+                    },
+                    "Expand all"
+                }
+                a {
+                    class: "cursor-pointer text-sky-500 text-sm",
+                    onclick: move |_| expanded.write().clear(),
+                    "Collapse all"
+                }
+            }
+
+            TreeNode {
+                name: root.name,
+                array: root.array,
+                path: Vec::new(),
+                current,
+                expanded,
+                history_stack,
+            }
+        }
+    }
+}
+
+#[component]
+fn TreeNode(
+    name: String,
+    array: SharedPtr<ArrayData>,
+    path: Vec<String>,
+    current: Vec<String>,
+    mut expanded: Signal<BTreeSet<Vec<String>>>,
+    mut history_stack: Signal<HistoryStack>,
+) -> Element {
+    let children = node_children(&array);
+    let is_expanded = expanded.read().contains(&path);
+    let is_current = path == current;
+    let indent = path.len() as f64;
+    let encoding = encoding_label(&array);
+    let size = humansize::format_size(node_nbytes(&array), humansize::BINARY);
+
+    let toggle_path = path.clone();
+    let goto_path = path.clone();
+
+    rsx! {
+        div {
+            class: "flex flex-row items-center gap-x-2 py-0.5 whitespace-nowrap",
+            style: "padding-left: {indent}rem",
+            if children.is_empty() {
+                span { class: "w-4" }
+            } else {
+                span {
+                    class: "w-4 text-xs cursor-pointer hover:text-sky-500",
+                    onclick: move |_| {
+                        let mut expanded = expanded.write();
+                        if !expanded.remove(&toggle_path) {
+                            expanded.insert(toggle_path.clone());
+                        }
+                    },
+                    if is_expanded {
+                        "▼"
+                    } else {
+                        "►"
+                    }
+                }
+            }
+            span {
+                class: "font-sans text-sm cursor-pointer hover:text-sky-500",
+                class: if is_current { "font-bold text-sky-500" },
+                onclick: move |_| {
+                    history_stack.write().goto_path(&goto_path);
+                },
+                "{name}"
+            }
+            span { class: "font-mono text-xs opacity-70", "{encoding}" }
+            span { class: "font-mono text-xs opacity-50", "{size}" }
+        }
+
+        if is_expanded {
+            for (child_name , child) in children {
+                TreeNode {
+                    key: "{child_name}",
+                    name: child_name.clone(),
+                    array: SharedPtr(Arc::new(child)),
+                    path: path.iter().cloned().chain([child_name.clone()]).collect::<Vec<_>>(),
+                    current: current.clone(),
+                    expanded,
+                    history_stack,
+                }
+            }
+        }
+    }
+}
+
+/// Collect the paths of every node with children, for expanding the whole tree.
+fn collect_paths(array: &ArrayData, path: &mut Vec<String>, paths: &mut BTreeSet<Vec<String>>) {
+    let children = node_children(array);
+    if children.is_empty() {
+        return;
+    }
+
+    paths.insert(path.clone());
+    for (name, child) in children {
+        path.push(name);
+        collect_paths(&child, path, paths);
+        path.pop();
+    }
+}
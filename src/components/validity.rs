@@ -0,0 +1,116 @@
+use dioxus::prelude::*;
+use vortex::{
+    error::VortexResult,
+    validity::{ArrayValidity, LogicalValidity},
+    ArrayData, IntoArrayVariant,
+};
+
+use crate::{components::Heading, SharedPtr};
+
+/// Number of columns in the density strip at the default zoom level.
+const BASE_COLUMNS: usize = 512;
+const MAX_ZOOM: usize = 16;
+const STRIP_HEIGHT: usize = 32;
+
+/// Visualize where the nulls in an array are.
+///
+/// The logical validity is rendered as a density strip with one pixel column per block of rows.
+/// Each column is shaded by the fraction of nulls within its block.
+#[component]
+pub fn ValidityInfo(array: SharedPtr<ArrayData>) -> Element {
+    let mut zoom = use_signal(|| 1usize);
+    let row_count = array.len();
+
+    let inner = match array.logical_validity() {
+        LogicalValidity::AllValid(_) => rsx! {
+            p { "All {row_count} rows are valid" }
+        },
+        LogicalValidity::AllInvalid(_) => rsx! {
+            p { "All {row_count} rows are null" }
+        },
+        LogicalValidity::Array(_) if row_count == 0 => rsx! {
+            p { "The array has no rows" }
+        },
+        LogicalValidity::Array(validity) => {
+            let columns = (BASE_COLUMNS * zoom()).min(row_count).max(1);
+            let rows_per_column = row_count.div_ceil(columns);
+            let densities = null_densities(validity, rows_per_column)?;
+            let null_blocks = densities.iter().filter(|density| **density > 0.0).count();
+            let width = densities.len();
+
+            rsx! {
+                div { class: "flex flex-row items-center gap-x-2 py-2",
+                    button {
+                        class: "px-2 rounded border border-zinc-50/10 hover:bg-neutral-800/75",
+                        disabled: zoom() == 1,
+                        onclick: move |_| {
+                            zoom.set((zoom() / 2).max(1));
+                        },
+                        "−"
+                    }
+                    span { class: "font-mono text-sm", "{zoom}x" }
+                    button {
+                        class: "px-2 rounded border border-zinc-50/10 hover:bg-neutral-800/75",
+                        disabled: zoom() == MAX_ZOOM,
+                        onclick: move |_| {
+                            zoom.set((zoom() * 2).min(MAX_ZOOM));
+                        },
+                        "+"
+                    }
+                    p { class: "font-regular font-sans text-sm italic text-slate-300/30",
+                        "{rows_per_column} rows per column, {null_blocks} of {width} blocks contain nulls"
+                    }
+                }
+
+                div { class: "overflow-x-auto max-w-full",
+                    svg {
+                        width: "{width}",
+                        height: "{STRIP_HEIGHT}",
+                        view_box: "0 0 {width} {STRIP_HEIGHT}",
+                        preserve_aspect_ratio: "none",
+                        rect {
+                            x: "0",
+                            y: "0",
+                            width: "{width}",
+                            height: "{STRIP_HEIGHT}",
+                            fill: "rgb(20 184 166)",
+                        }
+                        // Only draw the blocks that contain nulls, the background covers the rest.
+                        for (column , density) in densities.into_iter().enumerate().filter(|(_, d)| *d > 0.0) {
+                            rect {
+                                x: "{column}",
+                                y: "0",
+                                width: "1",
+                                height: "{STRIP_HEIGHT}",
+                                fill: "rgb(239 68 68)",
+                                fill_opacity: "{density}",
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        Heading { text: "Validity" }
+
+        {inner}
+    }
+}
+
+/// Fraction of null rows within each consecutive block of `rows_per_block` rows.
+fn null_densities(validity: ArrayData, rows_per_block: usize) -> VortexResult<Vec<f64>> {
+    let rows_per_block = rows_per_block.max(1);
+    let bits = validity.into_bool()?.boolean_buffer();
+    let len = bits.len();
+
+    Ok((0..len)
+        .step_by(rows_per_block)
+        .map(|start| {
+            let block_len = rows_per_block.min(len - start);
+            let valid = bits.slice(start, block_len).count_set_bits();
+            (block_len - valid) as f64 / block_len as f64
+        })
+        .collect())
+}
@@ -0,0 +1,271 @@
+//! Native file access for the desktop build: open dialogs, recently opened files, memory-mapped
+//! reads and reloading files that change on disk.
+
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use bytes::Bytes;
+use dioxus::logger::tracing::{info, warn};
+use dioxus::prelude::*;
+use futures::channel::mpsc::{unbounded, UnboundedReceiver};
+use memmap2::Mmap;
+use notify_debouncer_mini::notify::{self, RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+
+use crate::error::{ReadError, ReadStage};
+use crate::file_util::{open_file, read_file, read_local_file, ReaderConfig};
+use crate::summary::{diff_trees, NodeChange};
+use crate::{OpenTabs, Tab};
+
+/// Maximum number of entries kept in the recent files list.
+const RECENT_FILES_LIMIT: usize = 10;
+
+/// How long a file has to stay unchanged before it is reloaded. Writers usually touch a file
+/// several times while rewriting it.
+const RELOAD_DELAY: Duration = Duration::from_millis(300);
+
+/// Files passed on the command line, opened once the app has started.
+///
+/// Provided as context when launching the app.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StartupFiles(pub Vec<PathBuf>);
+
+/// Open the [`StartupFiles`], if the app was launched with any.
+///
+/// Called once by the root component, after it has provided the tabs and reader config.
+pub fn use_startup_files() {
+    use_hook(|| {
+        if let Some(StartupFiles(paths)) = try_consume_context() {
+            let open_tabs = consume_context::<OpenTabs>();
+            let config = consume_context::<ReaderConfig>();
+            // Opening tabs writes to signals, which must not happen while rendering.
+            spawn(async move { open_paths(open_tabs, paths, config) });
+        }
+    });
+}
+
+/// Map a file into memory, so only the parts the reader touches are loaded.
+///
+/// Falls back to reading the whole file where it cannot be mapped, e.g. for empty files.
+pub fn read_path(path: &Path) -> io::Result<Bytes> {
+    let file = File::open(path)?;
+    // SAFETY: the mapping is only valid while nobody truncates the file underneath it. Writers
+    // that replace a file by renaming a new version over it leave the mapped one intact. Once a
+    // watched file has changed, every later version is read by `reload_file` into an owned
+    // buffer, so only the version first opened is mapped while its writer may still touch it.
+    match unsafe { Mmap::map(&file) } {
+        Ok(mmap) => Ok(Bytes::from_owner(mmap)),
+        Err(err) => {
+            warn!(
+                "could not map {}, reading it instead: {err}",
+                path.display()
+            );
+            std::fs::read(path).map(Bytes::from)
+        }
+    }
+}
+
+/// Copy a file that changed on disk into an owned buffer.
+///
+/// Its writer may still be rewriting it, and truncating a mapped file pulls the pages out from
+/// under the reader, so reloaded versions are never mapped.
+fn read_snapshot(path: &Path) -> io::Result<Bytes> {
+    std::fs::read(path).map(Bytes::from)
+}
+
+/// Ask for Vortex files with the native open dialog.
+pub async fn pick_files() -> Vec<PathBuf> {
+    rfd::AsyncFileDialog::new()
+        .set_title("Open Vortex files")
+        .add_filter("Vortex files", &["vortex"])
+        .pick_files()
+        .await
+        .unwrap_or_default()
+        .iter()
+        .map(|handle| handle.path().to_path_buf())
+        .collect()
+}
+
+/// Open each file in its own tab. The first file reuses the active tab if it is still empty.
+pub fn open_paths(open_tabs: OpenTabs, paths: Vec<PathBuf>, config: ReaderConfig) {
+    for (idx, path) in paths.into_iter().enumerate() {
+        let path = path.canonicalize().unwrap_or(path);
+        let mut tab = open_tabs.tab_for_file(idx == 0);
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+
+        tab.file_name.set(file_name.clone());
+        tab.source.set(None);
+        tab.path.set(Some(path.clone()));
+        remember_file(&path);
+
+        tab.load(read_local_file(
+            file_name,
+            path,
+            config.clone(),
+            tab.read_error,
+            tab.history_stack,
+        ));
+    }
+}
+
+fn recent_files_path() -> Option<PathBuf> {
+    Some(
+        dirs::config_dir()?
+            .join("vortex-app")
+            .join("recent-files.json"),
+    )
+}
+
+/// Recently opened files, most recent first.
+pub fn recent_files() -> Vec<PathBuf> {
+    recent_files_path()
+        .and_then(|path| std::fs::read(path).ok())
+        .and_then(|contents| serde_json::from_slice(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Move a file to the top of the recent files list.
+pub fn remember_file(path: &Path) {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut recent = recent_files();
+    recent.retain(|existing| *existing != path);
+    recent.insert(0, path);
+    recent.truncate(RECENT_FILES_LIMIT);
+    save_recent_files(&recent);
+}
+
+/// Remove a file from the recent files list, e.g. after it turned out to be gone.
+pub fn forget_file(path: &Path) {
+    let mut recent = recent_files();
+    recent.retain(|existing| existing != path);
+    save_recent_files(&recent);
+}
+
+fn save_recent_files(recent: &[PathBuf]) {
+    let Some(list_path) = recent_files_path() else {
+        return;
+    };
+    // Failing to remember a file should never get in the way of opening it.
+    let saved = serde_json::to_vec(recent)
+        .map_err(io::Error::other)
+        .and_then(|contents| {
+            if let Some(dir) = list_path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(&list_path, contents)
+        });
+    if let Err(err) = saved {
+        warn!(
+            "could not save recent files to {}: {err}",
+            list_path.display()
+        );
+    }
+}
+
+/// Watch a file for changes, for as long as the returned debouncer is kept alive.
+///
+/// The directory holding the file is watched rather than the file itself, so that files replaced by
+/// renaming a new version over them are still picked up.
+pub fn watch_file(
+    path: &Path,
+) -> notify::Result<(Debouncer<RecommendedWatcher>, UnboundedReceiver<()>)> {
+    let (sender, receiver) = unbounded();
+    let file = path.to_path_buf();
+    let mut debouncer = new_debouncer(
+        RELOAD_DELAY,
+        move |events: DebounceEventResult| match events {
+            Ok(events) if events.iter().any(|event| event.path == file) => {
+                let _ = sender.unbounded_send(());
+            }
+            Ok(_) => {}
+            Err(err) => warn!("error watching {}: {err}", file.display()),
+        },
+    )?;
+    debouncer
+        .watcher()
+        .watch(path.parent().unwrap_or(path), RecursiveMode::NonRecursive)?;
+    Ok((debouncer, receiver))
+}
+
+/// The outcome of reloading a file after it changed on disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reload {
+    /// Nodes whose encoding or size changed, or that were added or removed.
+    pub changes: Vec<NodeChange>,
+    /// Whether the node that was open before still exists, and is open again.
+    pub restored: bool,
+    /// Why the new version could not be read, in which case the old one is still shown.
+    pub error: Option<String>,
+}
+
+impl Reload {
+    fn failed(err: ReadError) -> Self {
+        Self {
+            changes: Vec::new(),
+            restored: false,
+            error: Some(err.to_string()),
+        }
+    }
+}
+
+/// Read a file again after it changed, going back to the node that was open in the old version.
+pub async fn reload_file(mut tab: Tab, path: PathBuf, config: ReaderConfig) {
+    info!("{} changed, reloading", path.display());
+    let file_name = tab.file_name.peek().clone();
+
+    // Without a previous version to compare against, this is just another attempt at opening it.
+    let Some(previous) = tab.history_stack.peek().root().cloned() else {
+        match read_snapshot(&path) {
+            Ok(contents) => {
+                tab.read_error.set(None);
+                read_file(
+                    file_name,
+                    contents,
+                    config,
+                    tab.read_error,
+                    tab.history_stack,
+                )
+                .await;
+            }
+            Err(err) => tab.read_error.set(Some(ReadError::new(
+                ReadStage::Fetch,
+                format!("Could not read {}: {err}", path.display()),
+            ))),
+        }
+        return;
+    };
+    let location = tab.history_stack.peek().path();
+
+    let contents = match read_snapshot(&path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            let message = format!("Could not read {}: {err}", path.display());
+            tab.reload.set(Some(Reload::failed(ReadError::new(
+                ReadStage::Fetch,
+                message,
+            ))));
+            return;
+        }
+    };
+
+    match open_file(contents.clone(), &config).await {
+        Ok(array) => {
+            let changes = diff_trees(&previous.array, &array);
+            let mut history_stack = tab.history_stack.write();
+            history_stack.open(file_name, array, contents);
+            let restored = history_stack.goto_path(&location);
+            tab.reload.set(Some(Reload {
+                changes,
+                restored,
+                error: None,
+            }));
+        }
+        // Writers often leave the file incomplete for a moment, keep showing the last good version.
+        Err(err) => tab.reload.set(Some(Reload::failed(err))),
+    }
+}
@@ -0,0 +1,295 @@
+//! Step-by-step parsing of a file, to find out how much of a broken file can still be read.
+
+use std::ops::Range;
+
+use bytes::Bytes;
+use vortex::buffer::Buffer;
+use vortex::dtype::DType;
+use vortex::file::{VortexReadBuilder, EOF_SIZE, MAGIC_BYTES};
+use vortex::flatbuffers::{dtype as fb_dtype, footer as fb_footer};
+
+use crate::file_util::{check_magic, check_version, failed_columns, read_columns, ReaderConfig};
+
+/// The outcome of one step of parsing a file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagnosticStep {
+    pub name: &'static str,
+    /// The bytes of the file read by this step, if it reads a specific section.
+    pub range: Option<Range<usize>>,
+    /// What was found, or why the step failed.
+    pub result: Result<String, String>,
+}
+
+/// Every step of parsing a file, up to and including the first one that failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnosis {
+    pub file_size: usize,
+    pub steps: Vec<DiagnosticStep>,
+    /// Offset of the section where parsing stopped, if it stopped at a known section.
+    pub failed_at: Option<usize>,
+}
+
+impl Diagnosis {
+    /// Whether parsing stopped before reaching the end.
+    pub fn failed(&self) -> bool {
+        self.steps.last().is_some_and(|step| step.result.is_err())
+    }
+
+    /// Record a step, returning its result if it succeeded.
+    ///
+    /// A failed step with a range is where parsing stopped. Steps without one, like decoding the
+    /// data, fail somewhere in the file that the reader does not tell.
+    fn step<T>(
+        &mut self,
+        name: &'static str,
+        range: Option<Range<usize>>,
+        result: Result<(T, String), String>,
+    ) -> Option<T> {
+        let (value, result) = match result {
+            Ok((value, detail)) => (Some(value), Ok(detail)),
+            Err(err) => {
+                self.failed_at = range.as_ref().map(|range| range.start);
+                (None, Err(err))
+            }
+        };
+        self.steps.push(DiagnosticStep {
+            name,
+            range,
+            result,
+        });
+        value
+    }
+}
+
+/// Parse as much of a file as possible, stopping at the first section that cannot be read.
+///
+/// The sections are checked from the end of the file inwards, in the order a reader visits them:
+/// the magic bytes and version, the postscript, the schema and the layout. Finally the whole file
+/// is read and decoded, one column at a time if decoding the whole file fails.
+pub async fn diagnose(contents: Bytes, config: &ReaderConfig) -> Diagnosis {
+    let mut diagnosis = Diagnosis {
+        file_size: contents.len(),
+        steps: Vec::new(),
+        failed_at: None,
+    };
+    let len = contents.len();
+
+    let eof = len.saturating_sub(EOF_SIZE)..len;
+    let magic =
+        check_magic(&contents).map(|()| ((), String::from_utf8_lossy(&MAGIC_BYTES).into_owned()));
+    let Some(()) = diagnosis.step("Magic bytes", Some(eof.clone()), magic) else {
+        return diagnosis;
+    };
+    let eof = &contents[eof];
+
+    let version = check_version(&contents).map(|version| ((), format!("version {version}")));
+    let Some(()) = diagnosis.step("Version", Some(len - EOF_SIZE..len - EOF_SIZE + 2), version)
+    else {
+        return diagnosis;
+    };
+
+    let postscript_size = u16::from_le_bytes([eof[2], eof[3]]) as usize;
+    let postscript_end = len - EOF_SIZE;
+    let postscript = postscript_end.saturating_sub(postscript_size)..postscript_end;
+    let Some((schema_offset, layout_offset)) = diagnosis.step(
+        "Postscript",
+        Some(postscript.clone()),
+        parse_postscript(&contents, postscript_size, postscript.clone()),
+    ) else {
+        return diagnosis;
+    };
+
+    let schema = schema_offset..layout_offset;
+    let Some(()) = diagnosis.step(
+        "Schema",
+        Some(schema.clone()),
+        parse_schema(&contents[schema]),
+    ) else {
+        return diagnosis;
+    };
+
+    let layout = layout_offset..postscript.start;
+    let Some(()) = diagnosis.step(
+        "Layout",
+        Some(layout.clone()),
+        parse_layout(&contents[layout.clone()]),
+    ) else {
+        return diagnosis;
+    };
+
+    let buffer = Buffer::from(contents);
+    let reader = VortexReadBuilder::new(buffer.clone(), config.layout_deserializer())
+        .build()
+        .await
+        .map(|reader| (reader, "encodings and layouts are all known".to_string()))
+        .map_err(|err| err.to_string());
+    let Some(reader) = diagnosis.step("Reader", Some(layout), reader) else {
+        return diagnosis;
+    };
+
+    let dtype = reader.dtype().clone();
+    let decoded = match reader.read_all().await {
+        Ok(array) => Ok((
+            (),
+            format!("{} rows, {} bytes", array.len(), array.nbytes()),
+        )),
+        Err(err) => {
+            let reads = read_columns(buffer, &dtype, config).await;
+            if reads.failed.is_empty() {
+                Err(err.to_string())
+            } else {
+                Err(format!(
+                    "{err}\ncolumns failing to decode on their own: {}",
+                    failed_columns(&reads.failed)
+                ))
+            }
+        }
+    };
+    diagnosis.step("Decode", None, decoded);
+
+    diagnosis
+}
+
+/// Read the offsets of the schema and layout from the postscript, checking they are in order.
+fn parse_postscript(
+    contents: &[u8],
+    postscript_size: usize,
+    postscript: Range<usize>,
+) -> Result<((usize, usize), String), String> {
+    if postscript.len() != postscript_size {
+        return Err(format!(
+            "postscript of {postscript_size} bytes does not fit in the file"
+        ));
+    }
+
+    let fb = flatbuffers::root::<fb_footer::Postscript>(&contents[postscript.clone()])
+        .map_err(|err| format!("invalid postscript: {err}"))?;
+    let schema_offset = fb.schema_offset() as usize;
+    let layout_offset = fb.layout_offset() as usize;
+    if schema_offset > layout_offset || layout_offset > postscript.start {
+        return Err(format!(
+            "schema offset {schema_offset} and layout offset {layout_offset} are out of order, \
+             the postscript starts at {}",
+            postscript.start
+        ));
+    }
+
+    Ok((
+        (schema_offset, layout_offset),
+        format!("schema at {schema_offset}, layout at {layout_offset}"),
+    ))
+}
+
+fn parse_schema(bytes: &[u8]) -> Result<((), String), String> {
+    let fb = flatbuffers::root::<fb_dtype::DType>(bytes)
+        .map_err(|err| format!("invalid schema: {err}"))?;
+    let dtype = DType::try_from(fb).map_err(|err| err.to_string())?;
+
+    Ok(((), dtype.to_string()))
+}
+
+fn parse_layout(bytes: &[u8]) -> Result<((), String), String> {
+    let fb = flatbuffers::root::<fb_footer::Layout>(bytes)
+        .map_err(|err| format!("invalid layout: {err}"))?;
+
+    // Count the layout nodes, as a rough measure of how much of the layout is there.
+    let mut nodes = 0;
+    let mut stack = vec![fb];
+    while let Some(layout) = stack.pop() {
+        nodes += 1;
+        stack.extend(layout.children().into_iter().flatten());
+    }
+
+    Ok(((), format!("{nodes} layout nodes")))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use vortex::file::VERSION;
+
+    use super::*;
+
+    /// The end of a file: the version, the size of the postscript and the magic bytes.
+    fn eof(version: u16, postscript_size: u16) -> Vec<u8> {
+        let mut eof = Vec::with_capacity(EOF_SIZE);
+        eof.extend_from_slice(&version.to_le_bytes());
+        eof.extend_from_slice(&postscript_size.to_le_bytes());
+        eof.extend_from_slice(&MAGIC_BYTES);
+        eof
+    }
+
+    fn diagnose_bytes(contents: Vec<u8>) -> Diagnosis {
+        block_on(diagnose(contents.into(), &ReaderConfig::default()))
+    }
+
+    fn failed_step(diagnosis: &Diagnosis) -> &'static str {
+        assert!(diagnosis.failed());
+        diagnosis.steps.last().unwrap().name
+    }
+
+    #[test]
+    fn shorter_than_the_footer() {
+        for len in [0, 1, MAGIC_BYTES.len(), EOF_SIZE - 1] {
+            let diagnosis = diagnose_bytes(eof(VERSION, 0)[EOF_SIZE - len..].to_vec());
+            assert_eq!(diagnosis.file_size, len);
+            assert_eq!(failed_step(&diagnosis), "Magic bytes");
+            assert_eq!(diagnosis.steps.len(), 1);
+            assert_eq!(diagnosis.failed_at, Some(0));
+        }
+    }
+
+    #[test]
+    fn bad_magic() {
+        let mut contents = eof(VERSION, 0);
+        contents[EOF_SIZE - 1] ^= 0xff;
+        let diagnosis = diagnose_bytes(contents);
+        assert_eq!(failed_step(&diagnosis), "Magic bytes");
+        assert_eq!(diagnosis.failed_at, Some(0));
+    }
+
+    #[test]
+    fn bad_version() {
+        let mut contents = vec![0; 16];
+        contents.extend(eof(VERSION + 1, 0));
+        let diagnosis = diagnose_bytes(contents);
+        assert_eq!(failed_step(&diagnosis), "Version");
+        assert_eq!(diagnosis.failed_at, Some(16));
+        assert!(diagnosis.steps[0].result.is_ok());
+    }
+
+    #[test]
+    fn postscript_beyond_the_start_of_the_file() {
+        let mut contents = vec![0; 16];
+        contents.extend(eof(VERSION, 17));
+        let diagnosis = diagnose_bytes(contents);
+        assert_eq!(failed_step(&diagnosis), "Postscript");
+        assert_eq!(diagnosis.steps.last().unwrap().range, Some(0..16));
+        assert_eq!(diagnosis.failed_at, Some(0));
+
+        let diagnosis = diagnose_bytes(eof(VERSION, u16::MAX));
+        assert_eq!(failed_step(&diagnosis), "Postscript");
+    }
+
+    #[test]
+    fn invalid_postscript() {
+        let mut contents = vec![0xff; 8];
+        contents.extend(eof(VERSION, 8));
+        let diagnosis = diagnose_bytes(contents);
+        assert_eq!(failed_step(&diagnosis), "Postscript");
+        assert_eq!(diagnosis.failed_at, Some(0));
+    }
+
+    #[test]
+    fn postscript_must_fit() {
+        assert!(parse_postscript(&[0; 4], 8, 0..4).is_err());
+    }
+
+    #[test]
+    fn invalid_schema_and_layout() {
+        assert!(parse_schema(&[]).is_err());
+        assert!(parse_schema(&[0xff; 4]).is_err());
+        assert!(parse_layout(&[]).is_err());
+        assert!(parse_layout(&[0xff; 4]).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+//! Errors from opening a file, with enough context to tell what went wrong and where.
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use vortex::error::VortexError;
+use vortex::ArrayData;
+
+use crate::SharedPtr;
+
+/// The step of opening a file that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadStage {
+    /// Downloading or reading the bytes of the file.
+    Fetch,
+    /// Checking the magic bytes and version at the end of the file.
+    Footer,
+    /// Reading the schema and deserializing the layout.
+    Layout,
+    /// Reading and decoding the arrays described by the layout.
+    Decode,
+}
+
+impl fmt::Display for ReadStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ReadStage::Fetch => "Fetching the file",
+            ReadStage::Footer => "Parsing the footer",
+            ReadStage::Layout => "Deserializing the layout",
+            ReadStage::Decode => "Decoding the data",
+        })
+    }
+}
+
+/// An error from opening a file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadError {
+    pub stage: ReadStage,
+    pub message: String,
+    /// Where in the file the error happened, e.g. which columns and chunks failed to decode.
+    pub context: Option<String>,
+    /// Messages of the underlying errors, outermost first.
+    pub chain: Vec<String>,
+    pub backtrace: Option<String>,
+    /// Whatever could still be read, if anything.
+    pub partial: Option<SharedPtr<ArrayData>>,
+    /// The bytes of the file, kept for running diagnostics on it.
+    pub contents: Option<SharedPtr<Bytes>>,
+}
+
+impl ReadError {
+    pub fn new(stage: ReadStage, message: impl Into<String>) -> Self {
+        Self {
+            stage,
+            message: message.into(),
+            context: None,
+            chain: Vec::new(),
+            backtrace: None,
+            partial: None,
+            contents: None,
+        }
+    }
+
+    pub fn from_vortex(stage: ReadStage, err: &VortexError) -> Self {
+        // Vortex errors print their backtrace after the message.
+        let display = err.to_string();
+        let (message, backtrace) = match display.split_once("\nBacktrace:\n") {
+            Some((message, backtrace)) => {
+                (message, Some(backtrace.trim()).filter(|b| !b.is_empty()))
+            }
+            None => (display.as_str(), None),
+        };
+
+        let mut chain = Vec::new();
+        let mut source = err.source();
+        while let Some(inner) = source {
+            chain.push(inner.to_string());
+            source = inner.source();
+        }
+
+        Self {
+            chain,
+            backtrace: backtrace.map(str::to_string),
+            ..Self::new(stage, message)
+        }
+    }
+
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    pub fn with_contents(mut self, contents: Bytes) -> Self {
+        self.contents = Some(SharedPtr(Arc::new(contents)));
+        self
+    }
+
+    pub fn with_partial(mut self, partial: ArrayData) -> Self {
+        self.partial = Some(SharedPtr(Arc::new(partial)));
+        self
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed: {}", self.stage, self.message)?;
+        if let Some(context) = &self.context {
+            write!(f, " ({context})")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+//! Embedding the explorer in other Dioxus apps.
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use dioxus::prelude::*;
+use vortex::ArrayData;
+
+use crate::{
+    components::{error::ReadErrorDetails, sample_files::DownloadIndicator, ExploreView},
+    error::ReadError,
+    file_util::{read_file, read_remote_file, DownloadProgress, ReaderConfig},
+    EncodingRegistry, HistoryStack, SharedPtr,
+};
+
+/// What a [`VortexExplorer`] shows.
+#[derive(Clone, PartialEq)]
+pub enum ExplorerSource {
+    /// An array that is already in memory, e.g. one produced in-process.
+    Array {
+        name: String,
+        array: SharedPtr<ArrayData>,
+    },
+    /// The bytes of a Vortex file.
+    File {
+        name: String,
+        contents: SharedPtr<Bytes>,
+    },
+    /// A Vortex file to download.
+    Url(String),
+}
+
+impl ExplorerSource {
+    pub fn array(name: impl Into<String>, array: ArrayData) -> Self {
+        Self::Array {
+            name: name.into(),
+            array: SharedPtr(Arc::new(array)),
+        }
+    }
+
+    pub fn file(name: impl Into<String>, contents: Bytes) -> Self {
+        Self::File {
+            name: name.into(),
+            contents: SharedPtr(Arc::new(contents)),
+        }
+    }
+
+    pub fn url(url: impl Into<String>) -> Self {
+        Self::Url(url.into())
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Self::Array { name, .. } | Self::File { name, .. } => name.clone(),
+            Self::Url(url) => url.rsplit('/').next().unwrap_or(url).to_string(),
+        }
+    }
+}
+
+/// Explorer for a single array or file, for embedding in other Dioxus apps.
+///
+/// The source is reloaded whenever it changes. The `registry` and `config` default to the
+/// built-in encodings, and are only read when the explorer is first rendered.
+#[component]
+pub fn VortexExplorer(
+    source: ExplorerSource,
+    registry: Option<EncodingRegistry>,
+    config: Option<ReaderConfig>,
+) -> Element {
+    use_context_provider(|| registry.unwrap_or_else(EncodingRegistry::builtin));
+    let config = use_context_provider(|| config.unwrap_or_default());
+
+    let mut file_name = use_signal(String::new);
+    let mut read_error = use_signal(|| None::<ReadError>);
+    let mut download = use_signal(|| None::<DownloadProgress>);
+    let mut history_stack = use_signal(HistoryStack::empty);
+    // The read of the current source, cancelled when the source changes so that a slow read of
+    // an old source cannot overwrite the new one.
+    let mut reading = use_signal(|| None::<Task>);
+
+    use_effect(use_reactive!(|source| {
+        if let Some(task) = reading.take() {
+            task.cancel();
+        }
+
+        let name = source.name();
+        file_name.set(name.clone());
+        read_error.set(None);
+        download.set(None);
+        history_stack.write().clear();
+
+        let config = config.clone();
+        let task = match source {
+            ExplorerSource::Array { array, .. } => {
+                history_stack.write().push(name, (*array).clone());
+                None
+            }
+            ExplorerSource::File { contents, .. } => Some(spawn(async move {
+                read_file(name, (*contents).clone(), config, read_error, history_stack).await;
+            })),
+            ExplorerSource::Url(url) => Some(spawn(async move {
+                read_remote_file(name, url, config, read_error, history_stack, download).await;
+            })),
+        };
+        reading.set(task);
+    }));
+
+    rsx! {
+        if let Some(error) = read_error() {
+            ReadErrorDetails { error }
+        } else if history_stack().is_empty() {
+            if let Some(progress) = download() {
+                DownloadIndicator { file_name: file_name(), progress }
+            } else {
+                p { class: "font-sans text-sm italic", "Loading…" }
+            }
+        } else {
+            ExploreView { file_name: file_name(), history_stack }
+        }
+    }
+}
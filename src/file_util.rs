@@ -1,36 +1,459 @@
+use std::fmt;
+use std::pin::pin;
+use std::sync::Arc;
+
+use dioxus::logger::tracing::{info, warn};
 use dioxus::prelude::*;
+use dioxus_elements::FileEngine;
 
+use crate::error::{ReadError, ReadStage};
 use crate::HistoryStack;
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
+use futures::StreamExt;
+use vortex::array::{ChunkedArray, StructArray};
 use vortex::buffer::Buffer;
-use vortex::file::{LayoutContext, LayoutDeserializer, VortexReadBuilder};
+use vortex::dtype::DType;
+use vortex::file::{
+    LayoutContext, LayoutDeserializer, Projection, VortexReadBuilder, EOF_SIZE, MAGIC_BYTES,
+    VERSION,
+};
+use vortex::flatbuffers::{dtype as fb_dtype, footer as fb_footer};
 use vortex::sampling_compressor::ALL_ENCODINGS_CONTEXT;
+use vortex::validity::Validity;
+use vortex::{ArrayData, Context, IntoArrayData};
+
+/// The encodings and layouts known when reading files.
+///
+/// Provided as context at the root of the app. Code embedding the explorer can replace it to read
+/// files containing its own encodings or layouts.
+#[derive(Clone)]
+pub struct ReaderConfig {
+    pub encodings: Arc<Context>,
+    pub layouts: Arc<LayoutContext>,
+}
+
+impl Default for ReaderConfig {
+    fn default() -> Self {
+        Self {
+            encodings: ALL_ENCODINGS_CONTEXT.clone(),
+            layouts: LayoutContext::default().into(),
+        }
+    }
+}
+
+// Compare by identity, as the contexts are not comparable themselves.
+impl PartialEq for ReaderConfig {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.encodings, &other.encodings) && Arc::ptr_eq(&self.layouts, &other.layouts)
+    }
+}
+
+impl ReaderConfig {
+    pub fn layout_deserializer(&self) -> LayoutDeserializer {
+        LayoutDeserializer::new(self.encodings.clone(), self.layouts.clone())
+    }
+}
 
 pub async fn read_file(
     file: String,
     contents: Bytes,
-    mut read_error: Signal<Option<String>>,
+    config: ReaderConfig,
+    mut read_error: Signal<Option<ReadError>>,
     mut history_stack: Signal<HistoryStack>,
 ) {
+    match open_file(contents.clone(), &config).await {
+        Ok(array) => {
+            *read_error.write() = None;
+            // Start a fresh stack with the file at its root.
+            history_stack.write().open(file, array, contents);
+        }
+        Err(err) => *read_error.write() = Some(err.with_contents(contents)),
+    }
+}
+
+/// Read a whole file into memory.
+pub async fn open_file(contents: Bytes, config: &ReaderConfig) -> Result<ArrayData, ReadError> {
+    check_footer(&contents)?;
+
     let contents = Buffer::from(contents);
     // Create a new VortexFileReader and send the data to it.
-    let layout_serde = LayoutDeserializer::new(
-        ALL_ENCODINGS_CONTEXT.clone(),
-        LayoutContext::default().into(),
-    );
+    let reader = match VortexReadBuilder::new(contents.clone(), config.layout_deserializer())
+        .build()
+        .await
+    {
+        Ok(reader) => reader,
+        Err(err) => {
+            let err = ReadError::from_vortex(ReadStage::Layout, &err);
+            // An unknown layout only stops the columns below it from being read, so the other
+            // columns can still be shown.
+            return Err(match read_schema(contents.as_slice()) {
+                Some(dtype) => read_columns(contents, &dtype, config).await.annotate(err),
+                None => err,
+            });
+        }
+    };
+    let dtype = reader.dtype().clone();
 
-    match VortexReadBuilder::new(contents, layout_serde).build().await {
+    match reader.read_all().await {
+        Ok(array) => Ok(array),
         Err(err) => {
-            *read_error.write() = Some(err.to_string());
-        }
-        Ok(reader) => match reader.read_all().await {
-            Ok(array) => {
-                *read_error.write() = None;
-                // Push onto the front of the stack.
-                history_stack.write().clear();
-                history_stack.write().push(file, array);
-            }
-            Err(err) => *read_error.write() = Some(err.to_string()),
-        },
+            let err = ReadError::from_vortex(ReadStage::Decode, &err);
+            Err(read_columns(contents, &dtype, config).await.annotate(err))
+        }
+    }
+}
+
+/// Check the end of the file for the magic bytes and a supported version.
+fn check_footer(contents: &[u8]) -> Result<(), ReadError> {
+    check_magic(contents)
+        .and_then(|()| check_version(contents))
+        .map(|_| ())
+        .map_err(|message| ReadError::new(ReadStage::Footer, message))
+}
+
+/// Check that the file is long enough to hold the footer, and ends in the magic bytes.
+pub fn check_magic(contents: &[u8]) -> Result<(), String> {
+    if contents.len() < EOF_SIZE {
+        return Err(format!(
+            "file is {} bytes, too small to hold the {EOF_SIZE} byte footer",
+            contents.len()
+        ));
+    }
+
+    let magic = &contents[contents.len() - MAGIC_BYTES.len()..];
+    if magic != MAGIC_BYTES {
+        return Err(format!(
+            "invalid magic bytes {magic:?}, expected {MAGIC_BYTES:?}"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Read the version from the footer, checking it is supported. The footer has to pass
+/// [`check_magic`] first.
+pub fn check_version(contents: &[u8]) -> Result<u16, String> {
+    let eof = &contents[contents.len() - EOF_SIZE..];
+    let version = u16::from_le_bytes([eof[0], eof[1]]);
+    if version != VERSION {
+        return Err(format!(
+            "unsupported file version {version}, expected {VERSION}"
+        ));
+    }
+
+    Ok(version)
+}
+
+/// Read the schema straight from the footer, for when the reader cannot be built from the layout.
+fn read_schema(contents: &[u8]) -> Option<DType> {
+    let postscript_end = contents.len() - EOF_SIZE;
+    let eof = &contents[postscript_end..];
+    let postscript_size = u16::from_le_bytes([eof[2], eof[3]]) as usize;
+    let postscript = contents.get(postscript_end.checked_sub(postscript_size)?..postscript_end)?;
+    let postscript = flatbuffers::root::<fb_footer::Postscript>(postscript).ok()?;
+
+    let schema =
+        contents.get(postscript.schema_offset() as usize..postscript.layout_offset() as usize)?;
+    DType::try_from(flatbuffers::root::<fb_dtype::DType>(schema).ok()?).ok()
+}
+
+/// The outcome of reading each top-level column on its own.
+pub struct ColumnReads {
+    pub columns: Vec<(String, ArrayData)>,
+    pub failed: Vec<FailedColumn>,
+}
+
+/// A top-level column that could not be read.
+pub struct FailedColumn {
+    pub name: String,
+    /// Index of the chunk that failed to decode, `None` if reading failed before any chunk.
+    pub chunk: Option<usize>,
+}
+
+impl fmt::Display for FailedColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.chunk {
+            Some(chunk) => write!(f, "{} (chunk {chunk})", self.name),
+            None => f.write_str(&self.name),
+        }
+    }
+}
+
+/// Join the failed columns into a list for error messages.
+pub fn failed_columns(failed: &[FailedColumn]) -> String {
+    failed
+        .iter()
+        .map(|column| column.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl ColumnReads {
+    /// Add which columns failed, and the columns that could be read, to the error for the file.
+    fn annotate(self, err: ReadError) -> ReadError {
+        let err = if self.failed.is_empty() {
+            err
+        } else {
+            err.with_context(format!("failed columns: {}", failed_columns(&self.failed)))
+        };
+
+        if self.columns.is_empty() || self.failed.is_empty() {
+            return err;
+        }
+        let (names, fields): (Vec<_>, Vec<_>) = self.columns.into_iter().unzip();
+        let len = fields[0].len();
+        match StructArray::try_new(
+            names.into_iter().map(Arc::from).collect::<Vec<_>>().into(),
+            fields,
+            len,
+            Validity::NonNullable,
+        ) {
+            Ok(partial) => err.with_partial(partial.into_array()),
+            Err(_) => err,
+        }
+    }
+}
+
+/// Read every top-level column separately, to narrow down which of them fail to decode.
+pub async fn read_columns(contents: Buffer, dtype: &DType, config: &ReaderConfig) -> ColumnReads {
+    let mut reads = ColumnReads {
+        columns: Vec::new(),
+        failed: Vec::new(),
+    };
+    let Some(st) = dtype.as_struct() else {
+        return reads;
+    };
+
+    for (idx, name) in st.names().iter().enumerate() {
+        match read_column(contents.clone(), idx, config).await {
+            Ok(array) => reads.columns.push((name.to_string(), array)),
+            Err(chunk) => reads.failed.push(FailedColumn {
+                name: name.to_string(),
+                chunk,
+            }),
+        }
+    }
+
+    reads
+}
+
+/// Read a single top-level column chunk by chunk. On failure, returns the index of the chunk that
+/// could not be decoded, if reading got that far.
+async fn read_column(
+    contents: Buffer,
+    idx: usize,
+    config: &ReaderConfig,
+) -> Result<ArrayData, Option<usize>> {
+    let stream = VortexReadBuilder::new(contents, config.layout_deserializer())
+        .with_projection(Projection::new([idx]))
+        .build()
+        .await
+        .map_err(|_| None)?;
+    let dtype = stream.dtype().clone();
+
+    let mut stream = pin!(stream);
+    let mut chunks = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|_| Some(chunks.len()))?;
+        chunks.push(chunk);
+    }
+
+    if chunks.len() == 1 {
+        Ok(chunks.remove(0))
+    } else {
+        ChunkedArray::try_new(chunks, dtype)
+            .map(IntoArrayData::into_array)
+            .map_err(|_| None)
+    }
+}
+
+/// Read the first file selected or dropped by the user.
+pub async fn read_uploaded_file(
+    file_engine: Arc<dyn FileEngine>,
+    mut file_name: Signal<String>,
+    config: ReaderConfig,
+    read_error: Signal<Option<ReadError>>,
+    history_stack: Signal<HistoryStack>,
+) {
+    let files = file_engine.files();
+    let Some(file) = files.first() else {
+        return;
+    };
+    file_name.set(file.to_string());
+
+    read_selected_file(
+        file_engine,
+        file.to_string(),
+        config,
+        read_error,
+        history_stack,
+    )
+    .await;
+}
+
+/// Read one of the files selected or dropped by the user.
+pub async fn read_selected_file(
+    file_engine: Arc<dyn FileEngine>,
+    file: String,
+    config: ReaderConfig,
+    mut read_error: Signal<Option<ReadError>>,
+    history_stack: Signal<HistoryStack>,
+) {
+    match file_engine.read_file(&file).await {
+        Some(contents) => {
+            read_file(
+                file,
+                Bytes::from(contents),
+                config,
+                read_error,
+                history_stack,
+            )
+            .await
+        }
+        None => read_error.set(Some(ReadError::new(
+            ReadStage::Fetch,
+            format!("Could not read {file}"),
+        ))),
+    }
+}
+
+/// Read a file from disk by its path, memory-mapping it rather than loading it all at once.
+#[cfg(feature = "desktop")]
+pub async fn read_local_file(
+    file: String,
+    path: std::path::PathBuf,
+    config: ReaderConfig,
+    mut read_error: Signal<Option<ReadError>>,
+    history_stack: Signal<HistoryStack>,
+) {
+    info!("reading {}", path.display());
+    read_error.set(None);
+    match crate::desktop::read_path(&path) {
+        Ok(contents) => read_file(file, contents, config, read_error, history_stack).await,
+        Err(err) => read_error.set(Some(ReadError::new(
+            ReadStage::Fetch,
+            format!("Could not read {}: {err}", path.display()),
+        ))),
+    }
+}
+
+/// Progress of a file download.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DownloadProgress {
+    pub received: u64,
+    /// Size of the file, if the server reported one.
+    pub total: Option<u64>,
+}
+
+impl DownloadProgress {
+    /// Fraction of the file received so far, between 0 and 1.
+    pub fn fraction(&self) -> Option<f64> {
+        self.total
+            .filter(|&total| total > 0)
+            .map(|total| (self.received as f64 / total as f64).min(1.0))
+    }
+}
+
+/// Download a file, reporting progress as chunks arrive.
+///
+/// Fails on network errors, non-2xx responses and bodies shorter than the advertised length.
+pub async fn fetch_remote_file(
+    file_url: &str,
+    mut download: Signal<Option<DownloadProgress>>,
+) -> Result<Bytes, String> {
+    let response = reqwest::Client::new()
+        .get(file_url)
+        .send()
+        .await
+        .map_err(|err| format!("Failed to fetch {file_url}: {err}"))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!(
+            "Failed to fetch {file_url}: server responded with {status}"
+        ));
+    }
+
+    let total = response.content_length();
+    download.set(Some(DownloadProgress { received: 0, total }));
+
+    let mut contents = BytesMut::with_capacity(total.unwrap_or_default() as usize);
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|err| format!("Download of {file_url} failed: {err}"))?;
+        contents.extend_from_slice(&chunk);
+        download.set(Some(DownloadProgress {
+            received: contents.len() as u64,
+            total,
+        }));
+    }
+
+    if let Some(total) = total {
+        if (contents.len() as u64) < total {
+            return Err(format!(
+                "Download of {file_url} was truncated: received {} of {total} bytes",
+                contents.len()
+            ));
+        }
+    }
+
+    Ok(contents.freeze())
+}
+
+/// Fetch a file from a URL and read it.
+pub async fn read_remote_file(
+    file: String,
+    file_url: String,
+    config: ReaderConfig,
+    mut read_error: Signal<Option<ReadError>>,
+    history_stack: Signal<HistoryStack>,
+    mut download: Signal<Option<DownloadProgress>>,
+) {
+    info!("fetching {file_url}");
+    read_error.set(None);
+    let contents = fetch_remote_file(&file_url, download).await;
+    download.set(None);
+
+    match contents {
+        Ok(contents) => {
+            info!("completed request to server for {file}");
+            read_file(file, contents, config, read_error, history_stack).await;
+        }
+        Err(err) => read_error.set(Some(ReadError::new(ReadStage::Fetch, err))),
+    }
+}
+
+/// Hand a generated file to the browser as a download.
+#[cfg(not(feature = "desktop"))]
+pub async fn save_file(file_name: &str, contents: String, mime_type: &str) {
+    let eval = document::eval(
+        r#"
+        const [fileName, contents, mimeType] = await dioxus.recv();
+        const url = URL.createObjectURL(new Blob([contents], { type: mimeType }));
+        const link = document.createElement("a");
+        link.href = url;
+        link.download = fileName;
+        link.click();
+        URL.revokeObjectURL(url);
+        "#,
+    );
+    if let Err(err) = eval.send((file_name, contents, mime_type)) {
+        warn!("could not save {file_name}: {err}");
+    }
+}
+
+/// Ask where to save a generated file with the native save dialog, as the desktop webview does not
+/// support downloads.
+#[cfg(feature = "desktop")]
+pub async fn save_file(file_name: &str, contents: String, _mime_type: &str) {
+    let Some(handle) = rfd::AsyncFileDialog::new()
+        .set_file_name(file_name)
+        .save_file()
+        .await
+    else {
+        return;
+    };
+    if let Err(err) = std::fs::write(handle.path(), contents) {
+        warn!("could not save {}: {err}", handle.path().display());
     }
 }
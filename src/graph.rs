@@ -0,0 +1,191 @@
+//! Graph exports of the array tree, as Graphviz DOT or Mermaid text, and the layout of the
+//! diagram previewed in the app.
+
+use std::fmt::Write as _;
+
+use vortex::ArrayData;
+
+use crate::summary::{encoding_label, node_children, node_nbytes};
+
+/// A node of the array tree, as shown in a graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphNode {
+    pub name: String,
+    /// Names of the children leading from the root of the graph to this node.
+    pub path: Vec<String>,
+    pub encoding: String,
+    pub nbytes: usize,
+    /// Index of the parent node, `None` for the root of the graph.
+    pub parent: Option<usize>,
+}
+
+impl GraphNode {
+    pub fn size(&self) -> String {
+        humansize::format_size(self.nbytes, humansize::BINARY)
+    }
+}
+
+/// Flatten the tree under `array` in depth-first order, so every parent comes before its children.
+pub fn graph_nodes(name: &str, array: &ArrayData) -> Vec<GraphNode> {
+    fn visit(
+        name: String,
+        path: Vec<String>,
+        array: &ArrayData,
+        parent: Option<usize>,
+        nodes: &mut Vec<GraphNode>,
+    ) {
+        let idx = nodes.len();
+        nodes.push(GraphNode {
+            name,
+            path: path.clone(),
+            encoding: encoding_label(array),
+            nbytes: node_nbytes(array),
+            parent,
+        });
+        for (child_name, child) in node_children(array) {
+            let mut child_path = path.clone();
+            child_path.push(child_name.clone());
+            visit(child_name, child_path, &child, Some(idx), nodes);
+        }
+    }
+
+    let mut nodes = Vec::new();
+    visit(name.to_string(), Vec::new(), array, None, &mut nodes);
+    nodes
+}
+
+/// The graph as Graphviz DOT, laid out left to right.
+pub fn to_dot(nodes: &[GraphNode]) -> String {
+    let escape = |text: &str| text.replace('\\', "\\\\").replace('"', "\\\"");
+
+    let mut dot = String::from("digraph vortex {\n");
+    dot.push_str("  rankdir=LR;\n");
+    dot.push_str("  node [shape=box, style=rounded, fontname=\"monospace\"];\n");
+    for (idx, node) in nodes.iter().enumerate() {
+        let _ = writeln!(
+            dot,
+            "  n{idx} [label=\"{}\\n{}\\n{}\"];",
+            escape(&node.name),
+            escape(&node.encoding),
+            node.size()
+        );
+    }
+    for (idx, node) in nodes.iter().enumerate() {
+        if let Some(parent) = node.parent {
+            let _ = writeln!(dot, "  n{parent} -> n{idx};");
+        }
+    }
+    dot.push('}');
+    dot
+}
+
+/// The graph as a Mermaid flowchart, laid out left to right.
+pub fn to_mermaid(nodes: &[GraphNode]) -> String {
+    // Mermaid labels are HTML, with its own entity syntax for characters that would end the label.
+    let escape = |text: &str| {
+        text.replace('"', "#quot;")
+            .replace('<', "#lt;")
+            .replace('>', "#gt;")
+    };
+
+    let mut mermaid = String::from("flowchart LR\n");
+    for (idx, node) in nodes.iter().enumerate() {
+        let _ = writeln!(
+            mermaid,
+            "  n{idx}[\"{}<br/>{}<br/>{}\"]",
+            escape(&node.name),
+            escape(&node.encoding),
+            node.size()
+        );
+    }
+    for (idx, node) in nodes.iter().enumerate() {
+        if let Some(parent) = node.parent {
+            let _ = writeln!(mermaid, "  n{parent} --> n{idx}");
+        }
+    }
+    mermaid.trim_end().to_string()
+}
+
+/// Row of every node in a left to right drawing of the tree.
+///
+/// Leaves take one row each, in order, and parents are centered on their children. Rows are
+/// fractional for parents with an even number of children.
+pub fn layout_rows(nodes: &[GraphNode]) -> Vec<f64> {
+    let mut children = vec![Vec::new(); nodes.len()];
+    for (idx, node) in nodes.iter().enumerate() {
+        if let Some(parent) = node.parent {
+            children[parent].push(idx);
+        }
+    }
+
+    // Leaves are numbered in depth-first order.
+    let mut rows = vec![0.0; nodes.len()];
+    let mut next_row = 0.0;
+    for (idx, row) in rows.iter_mut().enumerate() {
+        if children[idx].is_empty() {
+            *row = next_row;
+            next_row += 1.0;
+        }
+    }
+    // Children come after their parents, so walking backwards places every child first.
+    for idx in (0..nodes.len()).rev() {
+        if let (Some(first), Some(last)) = (children[idx].first(), children[idx].last()) {
+            rows[idx] = (rows[*first] + rows[*last]) / 2.0;
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, parent: Option<usize>) -> GraphNode {
+        GraphNode {
+            name: name.to_string(),
+            path: Vec::new(),
+            encoding: "vortex.primitive".to_string(),
+            nbytes: 0,
+            parent,
+        }
+    }
+
+    #[test]
+    fn single_node() {
+        assert_eq!(layout_rows(&[node("root", None)]), vec![0.0]);
+    }
+
+    #[test]
+    fn leaves_take_one_row_each() {
+        let nodes = [
+            node("root", None),
+            node("a", Some(0)),
+            node("b", Some(0)),
+            node("c", Some(0)),
+        ];
+        assert_eq!(layout_rows(&nodes), vec![1.0, 0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn parents_are_centered_on_their_children() {
+        // root
+        // ├── a
+        // │   ├── a1
+        // │   └── a2
+        // └── b
+        let nodes = [
+            node("root", None),
+            node("a", Some(0)),
+            node("a1", Some(1)),
+            node("a2", Some(1)),
+            node("b", Some(0)),
+        ];
+        assert_eq!(layout_rows(&nodes), vec![1.25, 0.5, 0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn only_children_share_the_row_of_their_parent() {
+        let nodes = [node("root", None), node("a", Some(0)), node("b", Some(1))];
+        assert_eq!(layout_rows(&nodes), vec![0.0, 0.0, 0.0]);
+    }
+}
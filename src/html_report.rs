@@ -0,0 +1,297 @@
+//! Self-contained HTML report of a file, for sharing with people who don't have the explorer.
+//!
+//! Styles and charts are inlined, so the report is a single file that opens in any browser.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use vortex::{error::VortexResult, ArrayDType, ArrayData};
+
+use crate::components::array_info::EncodingRegistry;
+use crate::profile::profile_columns;
+use crate::summary::{
+    encoding_label, encoding_tree, node_children, node_nbytes, ColumnSummary, NodeSummary,
+    SchemaNode,
+};
+
+/// Most bars drawn in a chart, the rest are added up into a single bar.
+const MAX_BARS: usize = 20;
+const LABEL_WIDTH: usize = 220;
+const BAR_WIDTH: usize = 420;
+const BAR_HEIGHT: usize = 22;
+
+const STYLE: &str = "
+body { font-family: sans-serif; background: #171717; color: #f4f4f5; margin: 2rem; }
+h1 { font-size: 1.75rem; }
+h2 { font-size: 1.4rem; margin-top: 2.5rem; }
+table { border-collapse: collapse; min-width: 40%; }
+th { text-align: left; font-weight: normal; opacity: 0.7; padding: 0.5rem; background: #404040; }
+td { padding: 0.4rem 0.5rem; border-bottom: 1px solid rgba(250, 250, 250, 0.1); vertical-align: top; }
+td.label { font-weight: bold; }
+.mono, td.value { font-family: monospace; }
+.muted { opacity: 0.7; }
+ul.schema { font-family: monospace; list-style: none; padding-left: 1.25rem; }
+.nullable { color: #fbbf24; }
+";
+
+/// Render the HTML report of a file.
+pub fn html_report(
+    file_name: &str,
+    root: &ArrayData,
+    registry: &EncodingRegistry,
+) -> VortexResult<String> {
+    let summary = NodeSummary::try_new(root, registry)?;
+    let columns: Vec<(String, ColumnSummary)> = root
+        .dtype()
+        .as_struct()
+        .map(|st| {
+            st.names()
+                .iter()
+                .filter_map(|name| {
+                    ColumnSummary::try_new(root, name)
+                        .transpose()
+                        .map(|column| column.map(|column| (name.to_string(), column)))
+                })
+                .collect::<VortexResult<_>>()
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{title} - Vortex report</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+         <h1>{title}</h1>\n",
+        title = escape(file_name)
+    );
+
+    write_summary(&mut html, &summary);
+
+    if !columns.is_empty() {
+        html.push_str("<h2>Size by Column</h2>\n");
+        let sizes: Vec<(String, usize)> = columns
+            .iter()
+            .map(|(name, column)| (name.clone(), column.nbytes))
+            .collect();
+        write_bar_chart(&mut html, sizes);
+    }
+
+    html.push_str("<h2>Size by Encoding</h2>\n");
+    html.push_str(
+        "<p class=\"muted\">Bytes held by nodes of each encoding, excluding their children.</p>\n",
+    );
+    write_bar_chart(&mut html, bytes_by_encoding(root).into_iter().collect());
+
+    if !columns.is_empty() {
+        write_columns(&mut html, &columns, summary.nbytes);
+    }
+
+    // Profiling needs a struct at the root, other files just go without column statistics.
+    if root.dtype().as_struct().is_some() {
+        let profiles = profile_columns(root)?;
+        html.push_str("<h2>Column Statistics</h2>\n<table>\n<tr>");
+        for header in [
+            "Column",
+            "Null %",
+            "Distinct",
+            "Min",
+            "Max",
+            "Mean",
+            "Std Dev",
+            "Top Values",
+        ] {
+            let _ = write!(html, "<th>{header}</th>");
+        }
+        html.push_str("</tr>\n");
+        for profile in profiles {
+            let optional = |value: Option<String>| escape(&value.unwrap_or_default());
+            let top_values: Vec<String> = profile
+                .top_values
+                .iter()
+                .map(|(value, count)| {
+                    format!("{} ({})", escape(value), profile.format_top_count(*count))
+                })
+                .collect();
+            let _ = writeln!(
+                html,
+                "<tr><td class=\"label\">{}</td><td class=\"value\">{:.2}</td>\
+                 <td class=\"value\">{}</td><td class=\"value\">{}</td><td class=\"value\">{}</td>\
+                 <td class=\"value\">{}</td><td class=\"value\">{}</td><td class=\"value\">{}</td></tr>",
+                escape(&profile.name),
+                profile.null_pct(),
+                optional(profile.distinct_count.map(|count| format!("~{count}"))),
+                optional(profile.min.clone()),
+                optional(profile.max.clone()),
+                optional(profile.numeric.map(|numeric| format!("{:.4}", numeric.mean))),
+                optional(profile.numeric.map(|numeric| format!("{:.4}", numeric.stddev))),
+                top_values.join("<br>"),
+            );
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("<h2>Schema</h2>\n<ul class=\"schema\">\n");
+    write_schema(
+        &mut html,
+        &SchemaNode::new("root".to_string(), root.dtype()),
+    );
+    html.push_str("</ul>\n");
+
+    let _ = write!(
+        html,
+        "<h2>Encoding Tree</h2>\n<p class=\"mono\">{}</p>\n</body>\n</html>\n",
+        escape(&encoding_tree(root))
+    );
+
+    Ok(html)
+}
+
+fn write_summary(html: &mut String, summary: &NodeSummary) {
+    let null_pct = 100. * (summary.null_count as f64) / (summary.len.max(1) as f64);
+    let mut rows = vec![
+        (
+            "Size".to_string(),
+            humansize::format_size(summary.nbytes, humansize::BINARY),
+        ),
+        ("Row Count".to_string(), summary.len.to_string()),
+        ("DType".to_string(), summary.dtype.clone()),
+        ("Encoding".to_string(), summary.encoding.clone()),
+        (
+            "Null Count".to_string(),
+            format!("{} ({null_pct:.2}%)", summary.null_count),
+        ),
+    ];
+    rows.extend(summary.parameters.iter().cloned());
+    rows.extend(
+        summary
+            .stats
+            .iter()
+            .map(|(stat, value)| (stat.clone(), value.clone())),
+    );
+
+    html.push_str("<h2>Summary</h2>\n<table>\n");
+    for (label, value) in rows {
+        let _ = writeln!(
+            html,
+            "<tr><td class=\"label\">{}</td><td class=\"value\">{}</td></tr>",
+            escape(&label),
+            escape(&value)
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+fn write_columns(html: &mut String, columns: &[(String, ColumnSummary)], file_nbytes: usize) {
+    html.push_str(
+        "<h2>Columns</h2>\n<table>\n<tr><th>Column</th><th>DType</th><th>Rows</th>\
+         <th>Size</th><th>Share</th><th>Encodings</th></tr>\n",
+    );
+    for (name, column) in columns {
+        let share = 100. * (column.nbytes as f64) / (file_nbytes.max(1) as f64);
+        let encodings: Vec<String> = column.encodings.iter().map(|e| escape(e)).collect();
+        let _ = writeln!(
+            html,
+            "<tr><td class=\"label\">{}</td><td class=\"value\">{}</td><td class=\"value\">{}</td>\
+             <td class=\"value\">{}</td><td class=\"value\">{share:.1}%</td>\
+             <td class=\"value\">{}</td></tr>",
+            escape(name),
+            escape(&column.dtype),
+            column.len,
+            humansize::format_size(column.nbytes, humansize::BINARY),
+            encodings.join("<br>"),
+        );
+    }
+    html.push_str("</table>\n");
+}
+
+fn write_schema(html: &mut String, node: &SchemaNode) {
+    let nullable = if node.nullable {
+        " <span class=\"nullable\">nullable</span>"
+    } else {
+        ""
+    };
+    let _ = write!(
+        html,
+        "<li>{}: {}{nullable}",
+        escape(&node.name),
+        escape(&node.dtype)
+    );
+    if !node.fields.is_empty() {
+        html.push_str("\n<ul class=\"schema\">\n");
+        for field in &node.fields {
+            write_schema(html, field);
+        }
+        html.push_str("</ul>\n");
+    }
+    html.push_str("</li>\n");
+}
+
+/// Horizontal bar chart of byte sizes, largest first, as inline SVG.
+fn write_bar_chart(html: &mut String, mut bars: Vec<(String, usize)>) {
+    bars.sort_by(|a, b| b.1.cmp(&a.1));
+    if bars.len() > MAX_BARS {
+        let rest: usize = bars.drain(MAX_BARS - 1..).map(|(_, nbytes)| nbytes).sum();
+        bars.push(("(other)".to_string(), rest));
+    }
+
+    let max = bars
+        .iter()
+        .map(|(_, nbytes)| *nbytes)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let width = LABEL_WIDTH + BAR_WIDTH + 100;
+    let height = bars.len() * BAR_HEIGHT;
+    let _ = writeln!(
+        html,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         font-family=\"monospace\" font-size=\"12\">"
+    );
+    for (idx, (label, nbytes)) in bars.iter().enumerate() {
+        let y = idx * BAR_HEIGHT;
+        let bar = (BAR_WIDTH * nbytes / max).max(1);
+        let _ = writeln!(
+            html,
+            "<text x=\"{}\" y=\"{}\" fill=\"#f4f4f5\" text-anchor=\"end\">{}</text>\
+             <rect x=\"{LABEL_WIDTH}\" y=\"{}\" width=\"{bar}\" height=\"{}\" fill=\"#0ea5e9\"/>\
+             <text x=\"{}\" y=\"{}\" fill=\"#a1a1aa\">{}</text>",
+            LABEL_WIDTH - 8,
+            y + 15,
+            escape(label),
+            y + 3,
+            BAR_HEIGHT - 6,
+            LABEL_WIDTH + bar + 6,
+            y + 15,
+            humansize::format_size(*nbytes, humansize::BINARY),
+        );
+    }
+    html.push_str("</svg>\n");
+}
+
+/// Bytes held by the nodes of each encoding, not counting the bytes of their children.
+fn bytes_by_encoding(array: &ArrayData) -> BTreeMap<String, usize> {
+    fn visit(array: &ArrayData, totals: &mut BTreeMap<String, usize>) {
+        let children: Vec<ArrayData> = node_children(array)
+            .into_iter()
+            .map(|(_, child)| child)
+            .collect();
+        let children_nbytes: usize = children.iter().map(node_nbytes).sum();
+        *totals.entry(encoding_label(array)).or_default() +=
+            node_nbytes(array).saturating_sub(children_nbytes);
+        for child in &children {
+            visit(child, totals);
+        }
+    }
+
+    let mut totals = BTreeMap::new();
+    visit(array, &mut totals);
+    totals
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
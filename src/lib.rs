@@ -0,0 +1,930 @@
+//! Explorer for the structure of Vortex files.
+//!
+//! The explorer walks the tree of arrays making up a file, showing the encoding, statistics,
+//! validity, buffers and metadata of every node.
+//!
+//! Besides the standalone [`App`], the explorer can be embedded in other Dioxus apps with
+//! [`VortexExplorer`], for example to inspect arrays produced in-process:
+//!
+//! ```ignore
+//! use vortex_app::{ExplorerSource, VortexExplorer};
+//!
+//! #[component]
+//! fn Dashboard(array: ArrayData) -> Element {
+//!     rsx! {
+//!         VortexExplorer { source: ExplorerSource::array("orders", array) }
+//!     }
+//! }
+//! ```
+//!
+//! Panels for custom encodings are added through an [`EncodingRegistry`], and files using custom
+//! encodings or layouts are read with a [`ReaderConfig`] that knows about them.
+
+use std::future::Future;
+use std::ops::Deref;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::components::sample_files::SampleFiles;
+use crate::error::ReadError;
+use crate::file_util::{read_remote_file, DownloadProgress};
+use crate::routes::Route;
+use bytes::Bytes;
+use components::{
+    compare::CompareMode, error::FileErrorView, profile::ProfileView, tabs::TabBar, AppHeader,
+    ExploreView, ViewMode, ViewModeTabs,
+};
+use dioxus::logger::tracing::info;
+use dioxus::prelude::*;
+use dioxus_elements::{FileEngine, HasFileData};
+use vortex::ArrayData;
+
+mod array_util;
+#[cfg(not(target_arch = "wasm32"))]
+mod cli;
+mod components;
+#[cfg(feature = "desktop")]
+mod desktop;
+mod diagnostics;
+mod error;
+mod explorer;
+mod file_util;
+mod graph;
+mod html_report;
+mod profile;
+mod report;
+mod routes;
+mod scalar_fmt;
+mod search;
+mod sketch;
+mod summary;
+
+pub use components::array_info::{
+    registry::{EncodingPanel, SummaryMetrics},
+    DetailRenderer, EncodingRegistry,
+};
+pub use explorer::{ExplorerSource, VortexExplorer};
+pub use file_util::ReaderConfig;
+
+// Used by the binary.
+#[cfg(not(target_arch = "wasm32"))]
+pub use cli::run as run_cli;
+#[cfg(feature = "desktop")]
+pub use desktop::StartupFiles;
+
+const FAVICON: Asset = asset!("/assets/favicon.ico");
+const MAIN_CSS: Asset = asset!("/assets/main.css");
+const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
+const LOGO: Asset = asset!("/assets/logo.svg");
+
+/// The full explorer app, with tabs, file pickers and deep links.
+///
+/// Launch it with `dioxus::launch(App)`. To show a single array or file inside another app, use
+/// [`VortexExplorer`] instead.
+#[component]
+pub fn App() -> Element {
+    // Install a global drag handler so that drag-and-drop opening of files does not
+    // bubble up and cause the browser to "download" the file.
+    document::eval(
+        r#"
+        console.log("installing global window event listener for dragover/drop");
+        // Have window catch the dragover event.
+        window.addEventListener("dragover", function (evt) {
+            evt.preventDefault();
+        }, false);
+        window.addEventListener("drop", function (evt) {
+            evt.preventDefault();
+        }, false);
+        "#,
+    );
+
+    // Every open file gets its own tab. Start with a single empty tab showing the file picker.
+    use_context_provider(|| OpenTabs {
+        tabs: Signal::new(vec![Tab::new()]),
+        active_tab: Signal::new(0),
+    });
+    use_context_provider(EncodingRegistry::builtin);
+    use_context_provider(ReaderConfig::default);
+    #[cfg(feature = "desktop")]
+    desktop::use_startup_files();
+
+    rsx! {
+        document::Link { rel: "icon", href: FAVICON }
+        document::Link { rel: "stylesheet", href: MAIN_CSS }
+        document::Link { rel: "stylesheet", href: TAILWIND_CSS }
+        Router::<Route> {}
+    }
+}
+
+/// Maximum number of entries kept in the recently visited list.
+const RECENT_LIMIT: usize = 10;
+
+/// Navigation state for exploring an array tree.
+///
+/// The current path from the root to the node being viewed works like a stack. Alongside it, every
+/// navigation is recorded in a browser-like back/forward history, and the most recently visited
+/// nodes are kept for quick access.
+// Starts empty instead.
+#[derive(PartialEq, Clone)]
+pub(crate) struct HistoryStack {
+    inner: Vec<HistoryItem>,
+    back: Vec<Vec<HistoryItem>>,
+    forward: Vec<Vec<HistoryItem>>,
+    recent: Vec<Vec<HistoryItem>>,
+    /// The bytes of the file the root was read from, if they were kept.
+    contents: Option<SharedPtr<Bytes>>,
+}
+
+impl HistoryStack {
+    pub fn empty() -> Self {
+        Self {
+            inner: Vec::new(),
+            back: Vec::new(),
+            forward: Vec::new(),
+            recent: Vec::new(),
+            contents: None,
+        }
+    }
+
+    /// Start over from the root of a newly read file.
+    pub fn open(&mut self, name: String, array: ArrayData, contents: Bytes) {
+        self.clear();
+        self.push(name, array);
+        self.contents = Some(SharedPtr(Arc::new(contents)));
+    }
+
+    /// The bytes of the file being explored, if known.
+    pub fn contents(&self) -> Option<&SharedPtr<Bytes>> {
+        self.contents.as_ref()
+    }
+
+    pub fn push(&mut self, name: String, array: ArrayData) {
+        let previous = self.inner.clone();
+        self.push_item(name, array);
+        self.record(previous);
+    }
+
+    /// Clear the current path along with all history.
+    pub fn clear(&mut self) {
+        *self = Self::empty();
+    }
+
+    pub fn goto(&mut self, index: usize) {
+        let previous = self.inner.clone();
+        self.inner.truncate(index + 1);
+        self.record(previous);
+    }
+
+    /// Navigate to the parent of the current node.
+    pub fn up(&mut self) {
+        if self.len() > 1 {
+            self.goto(self.len() - 2);
+        }
+    }
+
+    /// Descend from the current item by following a path of child names.
+    ///
+    /// Each child along the path is pushed onto the stack. If any name cannot be resolved the stack
+    /// is left untouched and `false` is returned.
+    pub fn descend(&mut self, path: &[String]) -> bool {
+        let Some(resolved) = self.resolve(path) else {
+            return false;
+        };
+
+        let previous = self.inner.clone();
+        for (name, child) in resolved {
+            self.push_item(name, child);
+        }
+        self.record(previous);
+
+        true
+    }
+
+    fn resolve(&self, path: &[String]) -> Option<Vec<(String, ArrayData)>> {
+        let mut array = (*self.current()?.array).clone();
+        let mut resolved = Vec::with_capacity(path.len());
+        for name in path {
+            let child = find_child(&array, name)?;
+            resolved.push((name.clone(), child.clone()));
+            array = child;
+        }
+
+        Some(resolved)
+    }
+
+    /// The item for the whole file, at the bottom of the stack.
+    pub fn root(&self) -> Option<&HistoryItem> {
+        self.inner.first()
+    }
+
+    /// Names of the children leading from the root to the current item.
+    pub fn path(&self) -> Vec<String> {
+        item_path(&self.inner)
+    }
+
+    /// Navigate to the node at `path`, relative to the root.
+    ///
+    /// Descends as far along the path as possible, returning `false` if it could not be resolved
+    /// all the way.
+    pub fn goto_path(&mut self, path: &[String]) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let previous = self.inner.clone();
+        self.inner.truncate(1);
+        let mut resolved = true;
+        for name in path {
+            let Some(child) = self
+                .current()
+                .and_then(|item| find_child(&item.array, name))
+            else {
+                resolved = false;
+                break;
+            };
+            self.push_item(name.clone(), child);
+        }
+        self.record(previous);
+
+        resolved
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    /// Return to the previously viewed node.
+    pub fn back(&mut self) {
+        if let Some(previous) = self.back.pop() {
+            let current = std::mem::replace(&mut self.inner, previous);
+            self.forward.push(current);
+            self.remember();
+        }
+    }
+
+    /// Undo the last call to [`back`](Self::back).
+    pub fn forward(&mut self) {
+        if let Some(next) = self.forward.pop() {
+            let current = std::mem::replace(&mut self.inner, next);
+            self.back.push(current);
+            self.remember();
+        }
+    }
+
+    /// Paths of the most recently visited nodes, most recent first.
+    pub fn recent(&self) -> impl Iterator<Item = Vec<String>> + '_ {
+        self.recent.iter().map(|items| item_path(items))
+    }
+
+    /// Navigate to an entry of [`recent`](Self::recent).
+    pub fn goto_recent(&mut self, index: usize) {
+        if let Some(items) = self.recent.get(index).cloned() {
+            let previous = std::mem::replace(&mut self.inner, items);
+            self.record(previous);
+        }
+    }
+
+    pub fn current(&self) -> Option<&HistoryItem> {
+        self.inner.last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &HistoryItem> {
+        self.inner.iter()
+    }
+
+    fn push_item(&mut self, name: String, array: ArrayData) {
+        self.inner.push(HistoryItem {
+            name,
+            array: SharedPtr(Arc::new(array)),
+        });
+    }
+
+    /// Record a navigation away from `previous` in the back/forward history.
+    ///
+    /// Nodes are compared by their path, since every navigation resolves fresh copies of the arrays
+    /// along it.
+    fn record(&mut self, previous: Vec<HistoryItem>) {
+        if previous.is_empty() || item_path(&previous) == self.path() {
+            self.remember();
+            return;
+        }
+
+        self.back.push(previous);
+        self.forward.clear();
+        self.remember();
+    }
+
+    /// Move the current node to the front of the recently visited list.
+    fn remember(&mut self) {
+        if self.inner.is_empty() {
+            return;
+        }
+
+        let path = self.path();
+        self.recent.retain(|items| item_path(items) != path);
+        self.recent.insert(0, self.inner.clone());
+        self.recent.truncate(RECENT_LIMIT);
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub(crate) struct HistoryItem {
+    pub name: String,
+    pub array: SharedPtr<ArrayData>,
+}
+
+fn item_path(items: &[HistoryItem]) -> Vec<String> {
+    items.iter().skip(1).map(|item| item.name.clone()).collect()
+}
+
+/// Find the direct child of `array` with the given name.
+pub(crate) fn find_child(array: &ArrayData, name: &str) -> Option<ArrayData> {
+    summary::node_children(array)
+        .into_iter()
+        .find(|(child_name, _)| child_name == name)
+        .map(|(_, child)| child)
+}
+
+/// An open file, along with all of the state for exploring it.
+///
+/// A tab with an empty history shows the file picker, and opening a file loads it into that tab.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct Tab {
+    pub id: usize,
+    pub file_name: Signal<String>,
+    /// URL the file was fetched from. Only remote files can be linked to.
+    pub source: Signal<Option<String>>,
+    /// Where the file is on disk, for files the desktop app opened by path.
+    pub path: Signal<Option<PathBuf>>,
+    pub read_error: Signal<Option<ReadError>>,
+    /// Progress of the download, while a remote file is being fetched.
+    pub download: Signal<Option<DownloadProgress>>,
+    pub history_stack: Signal<HistoryStack>,
+    pub view_mode: Signal<ViewMode>,
+
+    // The second file, when comparing two files side by side.
+    pub compare_name: Signal<String>,
+    pub compare_error: Signal<Option<ReadError>>,
+    pub compare_stack: Signal<HistoryStack>,
+
+    /// What changed the last time the file was reloaded from disk.
+    #[cfg(feature = "desktop")]
+    pub reload: Signal<Option<desktop::Reload>>,
+
+    /// The task reading a file into the tab, cancelled when the tab is closed.
+    loading: Signal<Option<Task>>,
+}
+
+impl Default for Tab {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tab {
+    /// Create a new empty tab.
+    ///
+    /// Tabs are created from event handlers, so their state is owned by the root scope rather
+    /// than by whichever component happens to be running.
+    pub fn new() -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            file_name: Signal::new_in_scope(String::new(), ScopeId::ROOT),
+            source: Signal::new_in_scope(None, ScopeId::ROOT),
+            path: Signal::new_in_scope(None, ScopeId::ROOT),
+            read_error: Signal::new_in_scope(None, ScopeId::ROOT),
+            download: Signal::new_in_scope(None, ScopeId::ROOT),
+            history_stack: Signal::new_in_scope(HistoryStack::empty(), ScopeId::ROOT),
+            view_mode: Signal::new_in_scope(ViewMode::Explore, ScopeId::ROOT),
+            compare_name: Signal::new_in_scope(String::new(), ScopeId::ROOT),
+            compare_error: Signal::new_in_scope(None, ScopeId::ROOT),
+            compare_stack: Signal::new_in_scope(HistoryStack::empty(), ScopeId::ROOT),
+            #[cfg(feature = "desktop")]
+            reload: Signal::new_in_scope(None, ScopeId::ROOT),
+            loading: Signal::new_in_scope(None, ScopeId::ROOT),
+        }
+    }
+
+    /// Read a file into this tab in the background, replacing any read that is still running.
+    ///
+    /// The task belongs to the root scope, so it keeps going when the component that started it
+    /// is unmounted, and it is cancelled when the tab is closed.
+    pub fn load(mut self, read: impl Future<Output = ()> + 'static) {
+        if let Some(previous) = self.loading.replace(spawn_forever(read)) {
+            previous.cancel();
+        }
+    }
+
+    /// Release everything held by this tab once it has been closed.
+    ///
+    /// The signals belong to the root scope, so they would otherwise live as long as the app.
+    pub fn release(self) {
+        if let Some(task) = *self.loading.peek() {
+            task.cancel();
+        }
+        self.file_name.manually_drop();
+        self.source.manually_drop();
+        self.path.manually_drop();
+        self.read_error.manually_drop();
+        self.download.manually_drop();
+        self.history_stack.manually_drop();
+        self.view_mode.manually_drop();
+        self.compare_name.manually_drop();
+        self.compare_error.manually_drop();
+        self.compare_stack.manually_drop();
+        #[cfg(feature = "desktop")]
+        self.reload.manually_drop();
+        self.loading.manually_drop();
+    }
+}
+
+/// The set of open tabs.
+///
+/// Provided as context above the router, so tabs survive navigating between routes.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct OpenTabs {
+    pub tabs: Signal<Vec<Tab>>,
+    pub active_tab: Signal<usize>,
+}
+
+impl OpenTabs {
+    /// The tab to open a file in: the active tab if `reuse` is set and no file is open in it yet,
+    /// otherwise a new tab, which becomes the active one.
+    pub fn tab_for_file(mut self, reuse: bool) -> Tab {
+        let current = self.tabs.peek()[*self.active_tab.peek()];
+        if reuse && current.history_stack.peek().is_empty() {
+            return current;
+        }
+
+        let tab = Tab::new();
+        self.tabs.write().push(tab);
+        self.active_tab.set(self.tabs.peek().len() - 1);
+        tab
+    }
+}
+
+/// A link to a node within a remote file.
+#[derive(Clone, PartialEq)]
+pub(crate) struct DeepLink {
+    pub src: String,
+    pub path: Vec<String>,
+}
+
+#[component]
+fn Workspace(link: Option<DeepLink>) -> Element {
+    let open_tabs = use_context::<OpenTabs>();
+    let OpenTabs {
+        tabs,
+        mut active_tab,
+    } = open_tabs;
+    let config = use_context::<ReaderConfig>();
+
+    // Follow the link: open the linked file if it is not open yet, then navigate to the node.
+    use_effect(use_reactive!(|link| {
+        let Some(DeepLink { src, path }) = link else {
+            return;
+        };
+
+        let open_tab = tabs
+            .peek()
+            .iter()
+            .position(|tab| tab.source.peek().as_deref() == Some(src.as_str()));
+        if let Some(idx) = open_tab {
+            if *active_tab.peek() != idx {
+                active_tab.set(idx);
+            }
+            let mut history_stack = tabs.peek()[idx].history_stack;
+            if history_stack.peek().path() != path {
+                history_stack.write().goto_path(&path);
+            }
+            return;
+        }
+
+        let mut tab = open_tabs.tab_for_file(true);
+        let file = src.rsplit('/').next().unwrap_or(&src).to_string();
+        tab.file_name.set(file.clone());
+        tab.source.set(Some(src.clone()));
+        tab.path.set(None);
+        let config = config.clone();
+        tab.load(async move {
+            read_remote_file(
+                file,
+                src,
+                config,
+                tab.read_error,
+                tab.history_stack,
+                tab.download,
+            )
+            .await;
+            tab.history_stack.write().goto_path(&path);
+        });
+    }));
+
+    // Mirror the position in the active tab into the URL, so that views can be shared and the
+    // browser's back and forward buttons move through the visited nodes.
+    //
+    // Only navigation that happens after this route was mounted is pushed. Otherwise going back to
+    // the start page would immediately push the open file again, trapping the back button.
+    let mut last_position = use_signal(|| None::<(usize, Option<String>, Vec<String>)>);
+    use_effect(move || {
+        let tab = tabs.read()[active_tab()];
+        let history_stack = tab.history_stack.read();
+        let position = (tab.id, tab.source.read().clone(), history_stack.path());
+
+        let previous = last_position.peek().clone();
+        last_position.set(Some(position.clone()));
+        if previous.is_none() || previous == Some(position.clone()) || history_stack.is_empty() {
+            return;
+        }
+
+        let target = match position.1 {
+            Some(src) => Route::View {
+                src,
+                path: position.2.join("/"),
+            },
+            // Uploaded files cannot be reproduced from a link.
+            None => Route::Home {},
+        };
+        if router().current::<Route>() != target {
+            navigator().push(target);
+        }
+    });
+
+    // True when we have dragged a file but before we drop it.
+    let mut dropping = use_signal(|| false);
+
+    // Only called from event handlers, where the context can be looked up directly. This keeps the
+    // closure `Copy` so it can be shared with the tab.
+    let read_files = move |file_engine: Arc<dyn FileEngine>| {
+        // Files dropped onto the file input arrive here without a drop event on the dropzone.
+        let mut dropping = dropping;
+        dropping.set(false);
+        open_files(open_tabs, file_engine, consume_context::<ReaderConfig>());
+    };
+
+    let tab = tabs.read()[active_tab()];
+
+    rsx! {
+        // The entire app is a dropzone.
+        div {
+            class: "w-full h-screen",
+            ondragover: move |_| {
+                *dropping.write() = true;
+            },
+            ondragleave: move |_| {
+                *dropping.write() = false;
+            },
+            ondrop: move |evt| {
+                evt.prevent_default();
+                evt.stop_propagation();
+                *dropping.write() = false;
+                info!("ondrop event handler called");
+                if let Some(file_engine) = evt.files() {
+                    info!("files uploaded: {:?}", file_engine.files());
+                    read_files(file_engine);
+                }
+            },
+
+            // Navbar component
+            div {
+                class: "p-3 flex flex-row items-center gap-x-3",
+                class: "border-b border-gray-100/10",
+                AppHeader {}
+            }
+
+            TabBar { tabs, active_tab }
+
+            {file_watchers(tabs, active_tab)}
+
+            // Main content
+            div {
+                class: "w-full h-full px-4 py-4",
+                class: if dropping() { "border-teal-200 border-double border-2" },
+
+                TabContent {
+                    // Remount when switching tabs so per-node UI state does not leak between files.
+                    key: "{tab.id}",
+                    tab,
+                    other_tabs: tabs.read().iter().copied().filter(|other| other.id != tab.id).collect::<Vec<_>>(),
+                    read_files,
+                }
+            }
+        }
+    }
+}
+
+/// Open each file in its own tab. The first file reuses the active tab if it is still empty.
+#[cfg(not(feature = "desktop"))]
+fn open_files(open_tabs: OpenTabs, file_engine: Arc<dyn FileEngine>, config: ReaderConfig) {
+    for (idx, file) in file_engine.files().into_iter().enumerate() {
+        let mut tab = open_tabs.tab_for_file(idx == 0);
+        tab.file_name.set(file.clone());
+        tab.source.set(None);
+        tab.path.set(None);
+
+        tab.load(file_util::read_selected_file(
+            file_engine.clone(),
+            file,
+            config.clone(),
+            tab.read_error,
+            tab.history_stack,
+        ));
+    }
+}
+
+/// Open each file in its own tab. The desktop app is handed the paths of the files, which are
+/// memory-mapped where possible, and only read into memory when mapping fails.
+#[cfg(feature = "desktop")]
+fn open_files(open_tabs: OpenTabs, file_engine: Arc<dyn FileEngine>, config: ReaderConfig) {
+    let paths = file_engine.files().into_iter().map(PathBuf::from).collect();
+    desktop::open_paths(open_tabs, paths, config);
+}
+
+#[component]
+fn TabContent(
+    tab: Tab,
+    other_tabs: Vec<Tab>,
+    read_files: EventHandler<Arc<dyn FileEngine>>,
+) -> Element {
+    let Tab {
+        file_name,
+        source,
+        read_error,
+        download,
+        history_stack,
+        view_mode,
+        compare_name,
+        compare_error,
+        compare_stack,
+        ..
+    } = tab;
+
+    rsx! {
+        if let Some(error) = read_error() {
+            FileErrorView {
+                file_name,
+                source,
+                read_error,
+                download,
+                history_stack,
+                error,
+            }
+        } else if history_stack().is_empty() {
+            {file_picker(read_files)}
+            SampleFiles {
+                file_name,
+                source,
+                read_error,
+                download,
+                history_stack,
+            }
+        } else {
+            ViewModeTabs { view_mode }
+
+            if view_mode() == ViewMode::Profile {
+                if let Some(root) = history_stack().root() {
+                    ProfileView { array: root.array.clone() }
+                }
+            } else if view_mode() == ViewMode::Compare {
+                CompareMode {
+                    file_name: file_name(),
+                    history_stack,
+                    compare_name,
+                    compare_error,
+                    compare_stack,
+                    other_tabs,
+                }
+            } else {
+                ExploreView { file_name: file_name(), history_stack }
+            }
+        }
+    }
+}
+
+/// Picker for files on the user's machine.
+#[cfg(not(feature = "desktop"))]
+fn file_picker(read_files: EventHandler<Arc<dyn FileEngine>>) -> Element {
+    rsx! {
+        p { "Drop one or more Vortex files to view, or upload them below." }
+
+        input {
+            r#type: "file",
+            accept: ".vortex",
+            multiple: true,
+            onchange: move |evt| {
+                if let Some(file_engine) = evt.files() {
+                    read_files.call(file_engine);
+                }
+            },
+        }
+    }
+}
+
+/// Picker for files on the user's machine, using the native open dialog. Dropped files still
+/// arrive through `read_files`, so it is not needed here.
+#[cfg(feature = "desktop")]
+fn file_picker(_read_files: EventHandler<Arc<dyn FileEngine>>) -> Element {
+    rsx! {
+        p { "Drop one or more Vortex files to view, or open them below." }
+
+        components::recent_files::NativeFilePicker {}
+    }
+}
+
+/// Reload the files opened from disk whenever they change, noting what changed above the active tab.
+#[cfg(feature = "desktop")]
+fn file_watchers(tabs: Signal<Vec<Tab>>, active_tab: Signal<usize>) -> Element {
+    rsx! {
+        for (idx , tab) in tabs.read().iter().copied().enumerate() {
+            components::reload::FileWatcher { key: "{tab.id}", tab, active: idx == active_tab() }
+        }
+    }
+}
+
+/// Files opened in the browser cannot be watched.
+#[cfg(not(feature = "desktop"))]
+fn file_watchers(_tabs: Signal<Vec<Tab>>, _active_tab: Signal<usize>) -> Element {
+    rsx! {}
+}
+
+/// Wrapper around any Arc<T> to make it usable as a Dioxus Prop.
+///
+/// In Dioxus, all props need must be `PartialEq`. Not all of the Vortex types implement that trait,
+/// so this makes it easy for us to pass anything as a component prop at the expense of an added allocation.
+#[derive(Clone)]
+pub struct SharedPtr<T>(pub Arc<T>);
+
+// Deref impl allowing us to call immutable methods on `T` directly without unwrapping.
+impl<T> Deref for SharedPtr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+// Impl PartialEq that ensures two SharedPtr's have the same pointee.
+impl<T> PartialEq for SharedPtr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::validity::Validity;
+    use vortex::IntoArrayData;
+
+    use super::*;
+
+    fn column() -> ArrayData {
+        PrimitiveArray::from_vec(vec![1i32, 2, 3], Validity::NonNullable).into_array()
+    }
+
+    fn struct_of(fields: Vec<(&str, ArrayData)>) -> ArrayData {
+        let (names, fields): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
+        StructArray::try_new(
+            names.into_iter().map(Arc::from).collect::<Vec<_>>().into(),
+            fields,
+            3,
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array()
+    }
+
+    /// A stack opened on `{ a: { x }, b }`.
+    fn stack() -> HistoryStack {
+        let root = struct_of(vec![
+            ("a", struct_of(vec![("x", column())])),
+            ("b", column()),
+        ]);
+        let mut stack = HistoryStack::empty();
+        stack.open("file.vortex".to_string(), root, Bytes::new());
+        stack
+    }
+
+    fn path(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn back_and_forward() {
+        let mut stack = stack();
+        assert!(stack.goto_path(&path(&["a"])));
+        assert!(stack.goto_path(&path(&["a", "x"])));
+
+        stack.back();
+        assert_eq!(stack.path(), path(&["a"]));
+        stack.back();
+        assert_eq!(stack.path(), path(&[]));
+        assert!(!stack.can_go_back());
+
+        stack.forward();
+        stack.forward();
+        assert_eq!(stack.path(), path(&["a", "x"]));
+        assert!(!stack.can_go_forward());
+    }
+
+    #[test]
+    fn navigating_after_going_back_drops_forward_history() {
+        let mut stack = stack();
+        stack.goto_path(&path(&["a"]));
+        stack.goto_path(&path(&["a", "x"]));
+        stack.back();
+        assert!(stack.can_go_forward());
+
+        stack.goto_path(&path(&["b"]));
+        assert!(!stack.can_go_forward());
+        stack.back();
+        assert_eq!(stack.path(), path(&["a"]));
+    }
+
+    #[test]
+    fn staying_on_the_same_node_is_not_recorded() {
+        let mut stack = stack();
+        stack.goto_path(&path(&["a"]));
+        // Resolving the same path again yields fresh arrays, but the same node.
+        stack.goto_path(&path(&["a"]));
+        stack.up();
+        stack.up();
+
+        stack.back();
+        assert_eq!(stack.path(), path(&["a"]));
+        stack.back();
+        assert_eq!(stack.path(), path(&[]));
+        assert!(!stack.can_go_back());
+    }
+
+    #[test]
+    fn history_bounds() {
+        let mut empty = HistoryStack::empty();
+        empty.back();
+        empty.forward();
+        empty.up();
+        assert!(!empty.goto_path(&path(&["a"])));
+        assert!(empty.is_empty());
+
+        let mut stack = stack();
+        stack.back();
+        stack.forward();
+        stack.up();
+        assert_eq!(stack.path(), path(&[]));
+        assert!(!stack.can_go_back());
+        assert!(!stack.can_go_forward());
+    }
+
+    #[test]
+    fn unresolved_paths_stop_at_the_last_known_node() {
+        let mut stack = stack();
+        assert!(!stack.goto_path(&path(&["a", "missing", "x"])));
+        assert_eq!(stack.path(), path(&["a"]));
+
+        assert!(!stack.descend(&path(&["missing"])));
+        assert_eq!(stack.path(), path(&["a"]));
+    }
+
+    #[test]
+    fn recent_nodes_are_deduplicated_by_path() {
+        let mut stack = stack();
+        stack.goto_path(&path(&["a"]));
+        stack.goto_path(&path(&["b"]));
+        stack.goto_path(&path(&["a"]));
+
+        assert_eq!(
+            stack.recent().collect::<Vec<_>>(),
+            vec![path(&["a"]), path(&["b"]), path(&[])]
+        );
+    }
+
+    #[test]
+    fn recent_nodes_are_bounded() {
+        let names: Vec<String> = (0..RECENT_LIMIT + 2).map(|idx| format!("f{idx}")).collect();
+        let root = struct_of(names.iter().map(|name| (name.as_str(), column())).collect());
+        let mut stack = HistoryStack::empty();
+        stack.open("file.vortex".to_string(), root, Bytes::new());
+        for name in &names {
+            stack.goto_path(&[name.clone()]);
+        }
+
+        let recent: Vec<_> = stack.recent().collect();
+        assert_eq!(recent.len(), RECENT_LIMIT);
+        assert_eq!(recent[0], vec![names[RECENT_LIMIT + 1].clone()]);
+    }
+}
@@ -0,0 +1,406 @@
+//! Per-column profiling of a top-level struct array.
+//!
+//! Profiles are computed over the canonical form of each column, so they reflect the logical
+//! values regardless of how the column was encoded.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use vortex::{
+    accessor::ArrayAccessor,
+    dtype::DType,
+    error::{vortex_err, VortexResult},
+    stats::{ArrayStatistics, Stat},
+    validity::ArrayValidity,
+    ArrayDType, ArrayData, IntoArrayData, IntoArrayVariant,
+};
+
+use crate::array_util::{for_each_primitive, validity_mask, PrimitiveKey};
+use crate::find_child;
+use crate::scalar_fmt::format_scalar_as;
+use crate::sketch::HyperLogLog;
+
+/// Number of most frequent values to keep for each column.
+pub const TOP_VALUES: usize = 5;
+/// Number of candidate values tracked while looking for the most frequent ones.
+const TOP_CANDIDATES: usize = 128;
+
+/// Summary statistics for a single column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnProfile {
+    pub name: String,
+    pub dtype: DType,
+    pub row_count: usize,
+    pub null_count: usize,
+    /// Estimated number of distinct non-null values.
+    pub distinct_count: Option<usize>,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    /// Most frequent values and how often they occur, most frequent first.
+    pub top_values: Vec<(String, usize)>,
+    /// Whether the counts of the top values are exact. For columns with more distinct values than
+    /// can be tracked, they are lower bounds.
+    pub top_values_exact: bool,
+    pub numeric: Option<NumericProfile>,
+    pub lengths: Option<LengthProfile>,
+}
+
+impl ColumnProfile {
+    /// Format how often one of the top values occurs, marking counts that are only lower bounds.
+    pub fn format_top_count(&self, count: usize) -> String {
+        if self.top_values_exact {
+            count.to_string()
+        } else {
+            format!("≥{count}")
+        }
+    }
+
+    pub fn null_pct(&self) -> f64 {
+        if self.row_count == 0 {
+            0.0
+        } else {
+            100.0 * self.null_count as f64 / self.row_count as f64
+        }
+    }
+}
+
+/// Moments of a numeric column, ignoring nulls.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericProfile {
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+/// Byte lengths of a string or binary column, ignoring nulls.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LengthProfile {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+}
+
+/// Profile every field of a top-level struct array.
+pub fn profile_columns(array: &ArrayData) -> VortexResult<Vec<ColumnProfile>> {
+    struct_columns(array)?
+        .into_iter()
+        .map(|(name, column)| profile_column(name, &column))
+        .collect()
+}
+
+/// The fields of a top-level struct array, to profile one at a time.
+pub fn struct_columns(array: &ArrayData) -> VortexResult<Vec<(String, ArrayData)>> {
+    let struct_dtype = array
+        .dtype()
+        .as_struct()
+        .ok_or_else(|| vortex_err!("Profiling requires a struct array, found {}", array.dtype()))?;
+    // Canonicalize once so that chunked files expose their fields as direct children.
+    let canonical = array.clone().into_struct()?.into_array();
+
+    struct_dtype
+        .names()
+        .iter()
+        .map(|name| {
+            let column = find_child(&canonical, name)
+                .ok_or_else(|| vortex_err!("Missing field {name} in struct array"))?;
+            Ok((name.to_string(), column))
+        })
+        .collect()
+}
+
+/// Profile a single column.
+pub fn profile_column(name: String, column: &ArrayData) -> VortexResult<ColumnProfile> {
+    let dtype = column.dtype().clone();
+    let row_count = column.len();
+    let null_count = column.logical_validity().null_count()?;
+
+    let min = column
+        .statistics()
+        .compute(Stat::Min)
+        .map(|min| format_scalar_as(&dtype, &min));
+    let max = column
+        .statistics()
+        .compute(Stat::Max)
+        .map(|max| format_scalar_as(&dtype, &max));
+
+    let valid = validity_mask(column.logical_validity())?.map(|mask| mask.boolean_buffer());
+    let is_valid = |idx: usize| valid.as_ref().map_or(true, |valid| valid.value(idx));
+
+    let mut distinct = HyperLogLog::default();
+    let mut has_values = false;
+    let mut top_values = Vec::new();
+    let mut top_values_exact = true;
+    let mut numeric = None;
+    let mut lengths = None;
+
+    match &dtype {
+        DType::Bool(_) => {
+            let mut top = TopValues::<bool>::default();
+            let bools = column.clone().into_bool()?;
+            for (idx, value) in bools.boolean_buffer().iter().enumerate() {
+                if is_valid(idx) {
+                    distinct.insert(&value);
+                    top.add(&value);
+                    has_values = true;
+                }
+            }
+            top_values_exact = top.is_exact();
+            top_values = top.top(TOP_VALUES, bool::to_string);
+        }
+        DType::Primitive(..) => {
+            let mut top = TopValues::<PrimitiveKey>::default();
+            let mut moments = Moments::default();
+            for_each_primitive(column, |idx, value| {
+                if is_valid(idx) {
+                    distinct.insert(&value);
+                    top.add(&value);
+                    moments.add(value.as_f64());
+                    has_values = true;
+                }
+            })?;
+            top_values_exact = top.is_exact();
+            top_values = top.top(TOP_VALUES, |value| value.to_string());
+            numeric = moments.finish();
+        }
+        DType::Utf8(_) | DType::Binary(_) => {
+            let mut top = TopValues::<Vec<u8>>::default();
+            let mut length_stats = LengthStats::default();
+            column.clone().into_varbinview()?.with_iterator(|iter| {
+                for value in iter.flatten() {
+                    distinct.insert(value);
+                    top.add(value);
+                    length_stats.add(value.len());
+                    has_values = true;
+                }
+            })?;
+            top_values_exact = top.is_exact();
+            top_values = top.top(TOP_VALUES, |value| {
+                String::from_utf8_lossy(value).to_string()
+            });
+            lengths = length_stats.finish();
+        }
+        // Nested and extension types only report counts and min/max.
+        _ => {}
+    }
+
+    Ok(ColumnProfile {
+        name,
+        dtype,
+        row_count,
+        null_count,
+        distinct_count: has_values.then(|| distinct.estimate()),
+        min,
+        max,
+        top_values,
+        top_values_exact,
+        numeric,
+        lengths,
+    })
+}
+
+/// The most frequent values of a column, using the Misra-Gries algorithm.
+///
+/// At most [`TOP_CANDIDATES`] values are held at once, however many distinct values the column
+/// has. Counts are exact until a value finds no room, and lower bounds from then on.
+struct TopValues<K> {
+    counts: HashMap<K, usize>,
+    evicted: bool,
+}
+
+impl<K> Default for TopValues<K> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+            evicted: false,
+        }
+    }
+}
+
+impl<K: Hash + Eq> TopValues<K> {
+    fn add<Q>(&mut self, value: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(count) = self.counts.get_mut(value) {
+            *count += 1;
+        } else if self.counts.len() < TOP_CANDIDATES {
+            self.counts.insert(value.to_owned(), 1);
+        } else {
+            // No room for another candidate, so it cancels out one occurrence of every other.
+            self.evicted = true;
+            self.counts.retain(|_, count| {
+                *count -= 1;
+                *count > 0
+            });
+        }
+    }
+
+    fn is_exact(&self) -> bool {
+        !self.evicted
+    }
+
+    fn top(self, n: usize, format: impl Fn(&K) -> String) -> Vec<(String, usize)> {
+        let mut entries: Vec<(String, usize)> = self
+            .counts
+            .iter()
+            .map(|(value, count)| (format(value), *count))
+            .collect();
+        // Ties are broken by value so the output is stable across runs.
+        entries.sort_by(|(a_value, a_count), (b_value, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_value.cmp(b_value))
+        });
+        entries.truncate(n);
+        entries
+    }
+}
+
+/// Running mean and variance, using Welford's algorithm.
+#[derive(Default)]
+struct Moments {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn finish(self) -> Option<NumericProfile> {
+        (self.count > 0).then(|| NumericProfile {
+            mean: self.mean,
+            stddev: (self.m2 / self.count as f64).sqrt(),
+        })
+    }
+}
+
+#[derive(Default)]
+struct LengthStats {
+    count: usize,
+    total: usize,
+    min: usize,
+    max: usize,
+}
+
+impl LengthStats {
+    fn add(&mut self, len: usize) {
+        self.min = if self.count == 0 {
+            len
+        } else {
+            self.min.min(len)
+        };
+        self.max = self.max.max(len);
+        self.total += len;
+        self.count += 1;
+    }
+
+    fn finish(self) -> Option<LengthProfile> {
+        (self.count > 0).then(|| LengthProfile {
+            min: self.min,
+            max: self.max,
+            mean: self.total as f64 / self.count as f64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_values_are_exact_with_few_values() {
+        let mut top = TopValues::<String>::default();
+        for value in ["a", "b", "a", "c", "b", "a"] {
+            top.add(value);
+        }
+
+        assert!(top.is_exact());
+        assert_eq!(
+            top.top(2, String::clone),
+            vec![("a".to_string(), 3), ("b".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn top_values_break_ties_by_value() {
+        let mut top = TopValues::<String>::default();
+        for value in ["b", "a", "c"] {
+            top.add(value);
+        }
+
+        assert_eq!(
+            top.top(TOP_VALUES, String::clone),
+            vec![
+                ("a".to_string(), 1),
+                ("b".to_string(), 1),
+                ("c".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn top_values_evict_when_full() {
+        let mut top = TopValues::<usize>::default();
+        for _ in 0..10 {
+            top.add(&0);
+        }
+        // Fill the remaining candidates, then one more value cancels out one of each.
+        for value in 1..=TOP_CANDIDATES {
+            top.add(&value);
+        }
+
+        assert!(!top.is_exact());
+        // The frequent value survives with a count that undercounts, but never overcounts.
+        assert_eq!(top.top(2, usize::to_string), vec![("0".to_string(), 9)]);
+    }
+
+    #[test]
+    fn moments() {
+        let mut moments = Moments::default();
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            moments.add(value);
+        }
+
+        let profile = moments.finish().unwrap();
+        assert!((profile.mean - 5.0).abs() < 1e-12);
+        assert!((profile.stddev - 2.0).abs() < 1e-12);
+        assert_eq!(Moments::default().finish(), None);
+    }
+
+    #[test]
+    fn moments_of_a_single_value() {
+        let mut moments = Moments::default();
+        moments.add(-3.5);
+
+        assert_eq!(
+            moments.finish(),
+            Some(NumericProfile {
+                mean: -3.5,
+                stddev: 0.0
+            })
+        );
+    }
+
+    #[test]
+    fn length_stats() {
+        let mut lengths = LengthStats::default();
+        for len in [3, 1, 5] {
+            lengths.add(len);
+        }
+
+        assert_eq!(
+            lengths.finish(),
+            Some(LengthProfile {
+                min: 1,
+                max: 5,
+                mean: 3.0
+            })
+        );
+        assert_eq!(LengthStats::default().finish(), None);
+    }
+}
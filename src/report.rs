@@ -0,0 +1,102 @@
+//! Machine-readable report of the whole array tree of a file, for tracking how files are encoded
+//! across writer versions.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use vortex::{
+    error::{vortex_err, VortexResult},
+    ArrayData,
+};
+
+use crate::components::array_info::EncodingRegistry;
+use crate::summary::{node_children, serialize_pairs, NodeSummary};
+
+/// Version of the report format. Bumped whenever a field is removed or changes meaning.
+pub const REPORT_VERSION: u32 = 1;
+
+/// Report of every node of a file.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub version: u32,
+    pub file: String,
+    pub root: NodeReport,
+}
+
+/// A node of the array tree along with all of its descendants.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeReport {
+    pub name: String,
+    /// Names of the children leading from the root to this node, empty for the root itself.
+    pub path: Vec<String>,
+    pub encoding: String,
+    pub dtype: String,
+    pub len: usize,
+    pub nbytes: usize,
+    pub null_count: usize,
+    pub stats: BTreeMap<String, String>,
+    #[serde(serialize_with = "serialize_pairs")]
+    pub parameters: Vec<(String, String)>,
+    pub children: Vec<NodeReport>,
+}
+
+impl Report {
+    pub fn try_new(
+        file: &str,
+        root: &ArrayData,
+        registry: &EncodingRegistry,
+    ) -> VortexResult<Self> {
+        Ok(Self {
+            version: REPORT_VERSION,
+            file: file.to_string(),
+            root: NodeReport::try_new(file.to_string(), Vec::new(), root, registry)?,
+        })
+    }
+
+    pub fn to_json(&self) -> VortexResult<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|err| vortex_err!("Could not serialize the report: {err}"))
+    }
+}
+
+impl NodeReport {
+    fn try_new(
+        name: String,
+        path: Vec<String>,
+        array: &ArrayData,
+        registry: &EncodingRegistry,
+    ) -> VortexResult<Self> {
+        let NodeSummary {
+            encoding,
+            dtype,
+            len,
+            nbytes,
+            null_count,
+            stats,
+            parameters,
+            ..
+        } = NodeSummary::try_new(array, registry)?;
+
+        let children = node_children(array)
+            .into_iter()
+            .map(|(child_name, child)| {
+                let mut child_path = path.clone();
+                child_path.push(child_name.clone());
+                NodeReport::try_new(child_name, child_path, &child, registry)
+            })
+            .collect::<VortexResult<_>>()?;
+
+        Ok(Self {
+            name,
+            path,
+            encoding,
+            dtype,
+            len,
+            nbytes,
+            null_count,
+            stats,
+            parameters,
+            children,
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use dioxus::prelude::*;
+
+use crate::{DeepLink, Workspace};
+
+/// Routes of the app.
+///
+/// `/view` links to a node within a remote file, e.g.
+/// `/view?src=https://example.com/lineitem.vortex&path=l_orderkey/codes/encoded`.
+#[derive(Routable, Clone, PartialEq, Debug)]
+#[rustfmt::skip]
+pub enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/view?:src&:path")]
+    View { src: String, path: String },
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! {
+        Workspace { link: None }
+    }
+}
+
+#[component]
+fn View(src: String, path: String) -> Element {
+    let path = path
+        .split('/')
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect();
+
+    rsx! {
+        Workspace { link: DeepLink { src, path } }
+    }
+}
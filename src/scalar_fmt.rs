@@ -0,0 +1,206 @@
+//! Human-readable formatting for scalar values.
+//!
+//! Temporal extension types (timestamps, dates and times) are stored as plain integers, so their
+//! default `Display` impl shows raw epoch offsets. Every panel that shows values should format
+//! them through this module instead so they render consistently.
+
+use chrono::{DateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
+use vortex::datetime_dtype::{TemporalMetadata, TimeUnit};
+use vortex::dtype::{DType, ExtDType};
+use vortex::scalar::{ExtScalar, PrimitiveScalar, Scalar};
+use vortex::stats::Stat;
+
+/// Format a scalar for display.
+///
+/// Temporal extension scalars are rendered as ISO-8601 strings, everything else falls back to
+/// the scalar's `Display` impl.
+pub fn format_scalar(scalar: &Scalar) -> String {
+    if scalar.is_null() {
+        return "null".to_string();
+    }
+
+    if let DType::Extension(ext_dtype) = scalar.dtype() {
+        if let Ok(ext) = ExtScalar::try_from(scalar) {
+            if let Some(formatted) = format_extension(ext_dtype, &ext.storage()) {
+                return formatted;
+            }
+        }
+    }
+
+    scalar.to_string()
+}
+
+/// Format a scalar that belongs to an array of `dtype`.
+///
+/// Some values, such as statistics, are stored using the storage type of an extension array
+/// rather than the extension type itself. This reattaches the extension type before formatting.
+pub fn format_scalar_as(dtype: &DType, scalar: &Scalar) -> String {
+    match dtype {
+        DType::Extension(ext_dtype) if !matches!(scalar.dtype(), DType::Extension(_)) => {
+            if scalar.is_null() {
+                return "null".to_string();
+            }
+            format_extension(ext_dtype, scalar).unwrap_or_else(|| scalar.to_string())
+        }
+        _ => format_scalar(scalar),
+    }
+}
+
+/// Format the value of a statistic computed over an array of `dtype`.
+///
+/// Min and max share the array's type, the remaining stats are counts or flags.
+pub fn format_stat(dtype: &DType, stat: Stat, value: &Scalar) -> String {
+    match stat {
+        Stat::Min | Stat::Max => format_scalar_as(dtype, value),
+        _ => format_scalar(value),
+    }
+}
+
+/// Format the storage value of an extension scalar. Returns `None` for non-temporal extension
+/// types, or if the value is out of range for the type.
+fn format_extension(ext_dtype: &ExtDType, storage: &Scalar) -> Option<String> {
+    let metadata = TemporalMetadata::try_from(ext_dtype).ok()?;
+    let value = PrimitiveScalar::try_from(storage)
+        .ok()?
+        .as_::<i64>()
+        .ok()??;
+
+    match metadata {
+        TemporalMetadata::Timestamp(unit, tz) => format_timestamp(value, unit, tz.as_deref()),
+        TemporalMetadata::Date(unit) => format_date(value, unit),
+        TemporalMetadata::Time(unit) => format_time(value, unit),
+    }
+}
+
+fn format_timestamp(value: i64, unit: TimeUnit, tz: Option<&str>) -> Option<String> {
+    let (secs, nanos) = split_epoch(value, unit)?;
+    let timestamp: DateTime<Utc> = DateTime::from_timestamp(secs, nanos)?;
+
+    Some(match tz {
+        // Timezone-naive timestamps are shown without an offset.
+        None => timestamp
+            .naive_utc()
+            .format("%Y-%m-%dT%H:%M:%S%.f")
+            .to_string(),
+        Some(tz) => match tz.parse::<Tz>() {
+            Ok(zone) => format!(
+                "{} [{tz}]",
+                timestamp
+                    .with_timezone(&zone)
+                    .format("%Y-%m-%dT%H:%M:%S%.f%:z")
+            ),
+            // Fixed offsets and unrecognized zone names are shown in UTC alongside the zone.
+            Err(_) => format!(
+                "{}Z [{tz}]",
+                timestamp.naive_utc().format("%Y-%m-%dT%H:%M:%S%.f")
+            ),
+        },
+    })
+}
+
+fn format_date(value: i64, unit: TimeUnit) -> Option<String> {
+    let (secs, _) = split_epoch(value, unit)?;
+    let date = DateTime::from_timestamp(secs, 0)?.date_naive();
+    Some(date.format("%Y-%m-%d").to_string())
+}
+
+fn format_time(value: i64, unit: TimeUnit) -> Option<String> {
+    let (secs, nanos) = split_epoch(value, unit)?;
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(u32::try_from(secs).ok()?, nanos)?;
+    Some(format!("{} ({unit})", time.format("%H:%M:%S%.f")))
+}
+
+/// Split a value in the given unit into whole seconds and the sub-second nanoseconds.
+fn split_epoch(value: i64, unit: TimeUnit) -> Option<(i64, u32)> {
+    let per_second: i64 = match unit {
+        TimeUnit::Ns => 1_000_000_000,
+        TimeUnit::Us => 1_000_000,
+        TimeUnit::Ms => 1_000,
+        TimeUnit::S => 1,
+        TimeUnit::D => return Some((value.checked_mul(86_400)?, 0)),
+    };
+
+    let secs = value.div_euclid(per_second);
+    let nanos = value.rem_euclid(per_second) * (1_000_000_000 / per_second);
+    Some((secs, nanos as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_epoch_units() {
+        assert_eq!(
+            split_epoch(1_500_000_000, TimeUnit::Ns),
+            Some((1, 500_000_000))
+        );
+        assert_eq!(split_epoch(1_500_000, TimeUnit::Us), Some((1, 500_000_000)));
+        assert_eq!(split_epoch(1_500, TimeUnit::Ms), Some((1, 500_000_000)));
+        assert_eq!(split_epoch(2, TimeUnit::S), Some((2, 0)));
+        assert_eq!(split_epoch(2, TimeUnit::D), Some((172_800, 0)));
+        assert_eq!(split_epoch(i64::MAX, TimeUnit::D), None);
+    }
+
+    #[test]
+    fn split_epoch_before_1970() {
+        // Sub-second parts count forward from the previous whole second.
+        assert_eq!(split_epoch(-1, TimeUnit::Ms), Some((-1, 999_000_000)));
+        assert_eq!(split_epoch(-1, TimeUnit::Ns), Some((-1, 999_999_999)));
+        assert_eq!(split_epoch(-1_000, TimeUnit::Ms), Some((-1, 0)));
+        assert_eq!(split_epoch(-1, TimeUnit::D), Some((-86_400, 0)));
+    }
+
+    #[test]
+    fn timestamps() {
+        let format = |value, unit| format_timestamp(value, unit, None).unwrap();
+        assert_eq!(format(0, TimeUnit::S), "1970-01-01T00:00:00");
+        assert_eq!(format(1_500, TimeUnit::Ms), "1970-01-01T00:00:01.500");
+        assert_eq!(format(1_500_000, TimeUnit::Us), "1970-01-01T00:00:01.500");
+        assert_eq!(format(1, TimeUnit::Ns), "1970-01-01T00:00:00.000000001");
+        assert_eq!(format(-1, TimeUnit::Ms), "1969-12-31T23:59:59.999");
+        assert_eq!(format(-1, TimeUnit::Ns), "1969-12-31T23:59:59.999999999");
+    }
+
+    #[test]
+    fn timestamps_with_timezone() {
+        assert_eq!(
+            format_timestamp(0, TimeUnit::S, Some("America/New_York")).unwrap(),
+            "1969-12-31T19:00:00-05:00 [America/New_York]"
+        );
+        assert_eq!(
+            format_timestamp(-1, TimeUnit::Ms, Some("UTC")).unwrap(),
+            "1969-12-31T23:59:59.999+00:00 [UTC]"
+        );
+        // Offsets are not zone names, so the value is shown in UTC.
+        assert_eq!(
+            format_timestamp(0, TimeUnit::S, Some("+01:00")).unwrap(),
+            "1970-01-01T00:00:00Z [+01:00]"
+        );
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(format_date(0, TimeUnit::D).unwrap(), "1970-01-01");
+        assert_eq!(format_date(19_000, TimeUnit::D).unwrap(), "2022-01-08");
+        assert_eq!(format_date(-1, TimeUnit::D).unwrap(), "1969-12-31");
+        assert_eq!(format_date(86_400_000, TimeUnit::Ms).unwrap(), "1970-01-02");
+        assert_eq!(format_date(-1, TimeUnit::Ms).unwrap(), "1969-12-31");
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(
+            format_time(3_723, TimeUnit::S).unwrap(),
+            format!("01:02:03 ({})", TimeUnit::S)
+        );
+        assert_eq!(
+            format_time(3_723_500, TimeUnit::Ms).unwrap(),
+            format!("01:02:03.500 ({})", TimeUnit::Ms)
+        );
+        // Times of day cannot be negative or run past midnight.
+        assert_eq!(format_time(-1, TimeUnit::Ms), None);
+        assert_eq!(format_time(86_400, TimeUnit::S), None);
+    }
+}
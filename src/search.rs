@@ -0,0 +1,359 @@
+//! Searching the array tree by name, encoding, dtype or size.
+
+use std::cmp::Ordering;
+
+use vortex::{ArrayDType, ArrayData};
+
+use crate::summary::{encoding_label, node_children, node_nbytes};
+
+/// Characters that make up the comparison operators of a predicate.
+const OPERATOR_CHARS: &[char] = &['<', '>', '=', '!', ':'];
+
+/// A parsed search query. A node matches when it matches every term.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    terms: Vec<Term>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    /// Case-insensitive substring of the child name, encoding id or dtype.
+    Any(String),
+    /// Case-insensitive substring of a single text field.
+    Text(TextField, String),
+    /// Comparison of a numeric field against a value.
+    Compare(NumericField, Ordering, bool, u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TextField {
+    Name,
+    Encoding,
+    DType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumericField {
+    NBytes,
+    Len,
+    Children,
+}
+
+impl Query {
+    /// Parse a query such as `fsst`, `encoding:bitpacked name:codes` or `nbytes > 1MB`.
+    ///
+    /// Whitespace separates terms. Supported fields are `name`, `encoding` and `dtype`, compared
+    /// with `:` or `=`, and `nbytes` (alias `size`), `len` (alias `rows`) and `children`, compared
+    /// with `=`, `!=`, `<`, `<=`, `>` or `>=`. Sizes accept units such as `KB`, `MiB` or `GB`.
+    /// Double quotes keep spaces and operators in values, as in `name:"order id"`.
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let terms = tokenize(query)
+            .iter()
+            .map(|token| Term::parse(token))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { terms })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn matches(&self, name: &str, array: &ArrayData) -> bool {
+        self.terms.iter().all(|term| term.matches(name, array))
+    }
+}
+
+impl Term {
+    fn parse(token: &str) -> Result<Self, String> {
+        // Operators only count outside of quotes.
+        let unquoted = token.find('"').unwrap_or(token.len());
+        let Some(start) = token[..unquoted].find(OPERATOR_CHARS) else {
+            return Ok(Term::Any(unquote(token).to_lowercase()));
+        };
+        let end = token[start..]
+            .find(|c| !OPERATOR_CHARS.contains(&c))
+            .map_or(token.len(), |len| start + len);
+        let (field, operator) = (&token[..start], &token[start..end]);
+        let value = unquote(&token[end..]);
+        let value = value.as_str();
+        if value.is_empty() {
+            return Err(format!("missing value in \"{token}\""));
+        }
+
+        let text_field = match field.to_lowercase().as_str() {
+            "name" => Some(TextField::Name),
+            "encoding" => Some(TextField::Encoding),
+            "dtype" => Some(TextField::DType),
+            _ => None,
+        };
+        if let Some(text_field) = text_field {
+            return match operator {
+                ":" | "=" => Ok(Term::Text(text_field, value.to_lowercase())),
+                _ => Err(format!(
+                    "\"{field}\" can only be matched with \":\" or \"=\""
+                )),
+            };
+        }
+
+        let field = match field.to_lowercase().as_str() {
+            "nbytes" | "size" => NumericField::NBytes,
+            "len" | "rows" => NumericField::Len,
+            "children" => NumericField::Children,
+            _ => return Err(format!("unknown field \"{field}\"")),
+        };
+        let (ordering, negate) = match operator {
+            "=" | ":" => (Ordering::Equal, false),
+            "!=" => (Ordering::Equal, true),
+            "<" => (Ordering::Less, false),
+            ">=" => (Ordering::Less, true),
+            ">" => (Ordering::Greater, false),
+            "<=" => (Ordering::Greater, true),
+            _ => return Err(format!("unknown operator \"{operator}\"")),
+        };
+
+        Ok(Term::Compare(field, ordering, negate, parse_value(value)?))
+    }
+
+    fn matches(&self, name: &str, array: &ArrayData) -> bool {
+        match self {
+            Term::Any(needle) => [TextField::Name, TextField::Encoding, TextField::DType]
+                .into_iter()
+                .any(|field| field.value(name, array).contains(needle.as_str())),
+            Term::Text(field, needle) => field.value(name, array).contains(needle.as_str()),
+            Term::Compare(field, ordering, negate, value) => {
+                (field.value(array).cmp(value) == *ordering) != *negate
+            }
+        }
+    }
+}
+
+impl TextField {
+    fn value(&self, name: &str, array: &ArrayData) -> String {
+        match self {
+            TextField::Name => name.to_lowercase(),
+            TextField::Encoding => encoding_label(array).to_lowercase(),
+            TextField::DType => array.dtype().to_string().to_lowercase(),
+        }
+    }
+}
+
+impl NumericField {
+    fn value(&self, array: &ArrayData) -> u64 {
+        match self {
+            NumericField::NBytes => node_nbytes(array) as u64,
+            NumericField::Len => array.len() as u64,
+            NumericField::Children => node_children(array).len() as u64,
+        }
+    }
+}
+
+/// Split a query into terms, keeping predicates written with spaces, like `nbytes > 1MB`, together.
+///
+/// Quotes are kept in the terms, so that [`Term::parse`] can tell quoted text from operators.
+fn tokenize(query: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut join_next = false;
+    for word in words(query) {
+        let starts_with_operator = word.starts_with(OPERATOR_CHARS);
+        match tokens.last_mut() {
+            Some(last) if join_next || starts_with_operator => last.push_str(&word),
+            _ => tokens.push(word.clone()),
+        }
+        join_next = word.ends_with(OPERATOR_CHARS);
+    }
+
+    tokens
+}
+
+/// Split a query on whitespace outside of double quotes. An unterminated quote runs to the end.
+fn words(query: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut quoted = false;
+    for c in query.chars() {
+        if c == '"' {
+            quoted = !quoted;
+            word.push(c);
+        } else if c.is_whitespace() && !quoted {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+        } else {
+            word.push(c);
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn unquote(text: &str) -> String {
+    text.replace('"', "")
+}
+
+/// Parse a count or a size with an optional decimal or binary unit.
+fn parse_value(value: &str) -> Result<u64, String> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid number \"{value}\""))?;
+    let multiplier: u64 = match unit.to_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" => 1_000,
+        "m" | "mb" => 1_000_000,
+        "g" | "gb" => 1_000_000_000,
+        "kib" => 1 << 10,
+        "mib" => 1 << 20,
+        "gib" => 1 << 30,
+        _ => return Err(format!("unknown unit \"{unit}\"")),
+    };
+
+    Ok((number * multiplier as f64).round() as u64)
+}
+
+/// A node of the tree matching a search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchMatch {
+    /// Names of the children leading from the root to the node.
+    pub path: Vec<String>,
+    pub encoding: String,
+    pub dtype: String,
+    pub nbytes: usize,
+}
+
+/// Find every node below and including `root` matching the query, in depth-first order.
+pub fn search(root_name: &str, root: &ArrayData, query: &Query) -> Vec<SearchMatch> {
+    let mut matches = Vec::new();
+    search_node(root_name, root, &mut Vec::new(), query, &mut matches);
+    matches
+}
+
+fn search_node(
+    name: &str,
+    array: &ArrayData,
+    path: &mut Vec<String>,
+    query: &Query,
+    matches: &mut Vec<SearchMatch>,
+) {
+    if query.matches(name, array) {
+        matches.push(SearchMatch {
+            path: path.clone(),
+            encoding: encoding_label(array),
+            dtype: array.dtype().to_string(),
+            nbytes: node_nbytes(array),
+        });
+    }
+
+    for (child_name, child) in node_children(array) {
+        path.push(child_name.clone());
+        search_node(&child_name, &child, path, query, matches);
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(query: &str) -> Vec<Term> {
+        Query::parse(query).unwrap().terms
+    }
+
+    #[test]
+    fn bare_terms_match_any_field() {
+        assert_eq!(
+            terms("FSST  codes"),
+            vec![
+                Term::Any("fsst".to_string()),
+                Term::Any("codes".to_string())
+            ]
+        );
+        assert!(Query::parse("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn text_fields() {
+        assert_eq!(
+            terms("encoding:BitPacked name=codes"),
+            vec![
+                Term::Text(TextField::Encoding, "bitpacked".to_string()),
+                Term::Text(TextField::Name, "codes".to_string()),
+            ]
+        );
+        assert!(Query::parse("name>codes").is_err());
+    }
+
+    #[test]
+    fn predicates_written_with_spaces() {
+        let expected = vec![Term::Compare(
+            NumericField::NBytes,
+            Ordering::Greater,
+            false,
+            1_000_000,
+        )];
+        assert_eq!(terms("nbytes > 1MB"), expected);
+        assert_eq!(terms("nbytes >1MB"), expected);
+        assert_eq!(terms("nbytes> 1MB"), expected);
+        assert_eq!(terms("size>1mb"), expected);
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(
+            terms("rows<=10 children!=0 len>=5 rows=3"),
+            vec![
+                Term::Compare(NumericField::Len, Ordering::Greater, true, 10),
+                Term::Compare(NumericField::Children, Ordering::Equal, true, 0),
+                Term::Compare(NumericField::Len, Ordering::Less, true, 5),
+                Term::Compare(NumericField::Len, Ordering::Equal, false, 3),
+            ]
+        );
+        assert!(Query::parse("rows=>3").is_err());
+    }
+
+    #[test]
+    fn quoted_values() {
+        assert_eq!(
+            terms(r#"name:"Order ID""#),
+            vec![Term::Text(TextField::Name, "order id".to_string())]
+        );
+        assert_eq!(
+            terms(r#"name: "a b" fsst"#),
+            vec![
+                Term::Text(TextField::Name, "a b".to_string()),
+                Term::Any("fsst".to_string()),
+            ]
+        );
+        // Operators inside quotes are part of the text.
+        assert_eq!(terms(r#""a>b:c""#), vec![Term::Any("a>b:c".to_string())]);
+        // An unterminated quote runs to the end of the query.
+        assert_eq!(
+            terms(r#"name:"x y"#),
+            vec![Term::Text(TextField::Name, "x y".to_string())]
+        );
+    }
+
+    #[test]
+    fn invalid_predicates() {
+        assert!(Query::parse("nbytes >").is_err());
+        assert!(Query::parse("name:\"\"").is_err());
+        assert!(Query::parse("color=red").is_err());
+        assert!(Query::parse("size>abc").is_err());
+        assert!(Query::parse("size>1XB").is_err());
+    }
+
+    #[test]
+    fn values_with_units() {
+        assert_eq!(parse_value("42"), Ok(42));
+        assert_eq!(parse_value("1.5kb"), Ok(1_500));
+        assert_eq!(parse_value("2KiB"), Ok(2_048));
+        assert_eq!(parse_value("1MiB"), Ok(1 << 20));
+        assert_eq!(parse_value("3GB"), Ok(3_000_000_000));
+    }
+}
@@ -0,0 +1,397 @@
+//! Plain data describing arrays, shared by the views that compare or report on them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Serialize, Serializer};
+use vortex::{
+    array::{ChunkedArray, ChunkedEncoding},
+    dtype::DType,
+    encoding::{opaque::OpaqueEncoding, Encoding},
+    error::VortexResult,
+    stats::ArrayStatistics,
+    validity::ArrayValidity,
+    ArrayDType, ArrayData,
+};
+
+use crate::components::array_info::EncodingRegistry;
+use crate::components::dtype::{dtype_label, nested_dtypes};
+use crate::find_child;
+use crate::scalar_fmt::format_stat;
+
+/// The code of an encoding the reader did not know, which it kept as an opaque array.
+pub fn unknown_encoding(array: &ArrayData) -> Option<u16> {
+    array
+        .encoding()
+        .as_any()
+        .downcast_ref::<OpaqueEncoding>()
+        .map(|opaque| opaque.0)
+}
+
+/// The named children of an array. Arrays of unknown encodings cannot interpret their metadata to
+/// find their children, so they are treated as leaves.
+pub fn node_children(array: &ArrayData) -> Vec<(String, ArrayData)> {
+    if unknown_encoding(array).is_some() {
+        return Vec::new();
+    }
+    array.named_children()
+}
+
+/// Bytes held by an array and its descendants. Only the buffer of an array of unknown encoding is
+/// counted, as its children cannot be visited.
+pub fn node_nbytes(array: &ArrayData) -> usize {
+    if unknown_encoding(array).is_some() {
+        return array.buffer().map_or(0, |buffer| buffer.len());
+    }
+    array.nbytes()
+}
+
+/// Name of the encoding of an array, e.g. `fastlanes.bitpacked` or `unknown encoding 42`.
+pub fn encoding_label(array: &ArrayData) -> String {
+    match unknown_encoding(array) {
+        Some(code) => format!("unknown encoding {code}"),
+        None => array.encoding().id().to_string(),
+    }
+}
+
+/// Summary of a single node of the array tree.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeSummary {
+    pub encoding: String,
+    pub dtype: String,
+    pub len: usize,
+    pub nbytes: usize,
+    pub null_count: usize,
+    /// Formatted statistics, keyed by the name of the stat.
+    pub stats: BTreeMap<String, String>,
+    /// Encoding-specific parameters, such as the bit width of bit-packed arrays, in display order.
+    #[serde(serialize_with = "serialize_pairs")]
+    pub parameters: Vec<(String, String)>,
+}
+
+impl NodeSummary {
+    /// Summarize a node, taking the encoding parameters from the panels in `registry`.
+    pub fn try_new(array: &ArrayData, registry: &EncodingRegistry) -> VortexResult<Self> {
+        let dtype = array.dtype();
+        let stats = array
+            .statistics()
+            .to_set()
+            .into_iter()
+            .map(|(stat, value)| (stat.to_string(), format_stat(dtype, stat, &value)))
+            .collect();
+
+        Ok(Self {
+            encoding: encoding_label(array),
+            dtype: dtype.to_string(),
+            len: array.len(),
+            nbytes: node_nbytes(array),
+            null_count: array.logical_validity().null_count()?,
+            stats,
+            parameters: encoding_parameters(array, registry)?,
+        })
+    }
+}
+
+/// Encoding-specific parameters of an array, as provided by the panel registered for its encoding.
+pub fn encoding_parameters(
+    array: &ArrayData,
+    registry: &EncodingRegistry,
+) -> VortexResult<Vec<(String, String)>> {
+    match registry
+        .get(array.encoding().id())
+        .and_then(|panel| panel.summary)
+    {
+        Some(summary) => summary(array),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Serialize label and value pairs as a map, keeping their order.
+pub fn serialize_pairs<S: Serializer>(
+    pairs: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(pairs.iter().map(|(label, value)| (label, value)))
+}
+
+/// Describe the encodings of an array and its descendants, e.g.
+/// `vortex.dict(codes=fastlanes.bitpacked, values=vortex.varbinview)`.
+pub fn encoding_tree(array: &ArrayData) -> String {
+    let encoding = encoding_label(array);
+    let children = node_children(array);
+    if children.is_empty() {
+        return encoding;
+    }
+
+    let children: Vec<String> = children
+        .iter()
+        .map(|(name, child)| format!("{name}={}", encoding_tree(child)))
+        .collect();
+    format!("{encoding}({})", children.join(", "))
+}
+
+/// A DType and the DTypes nested in it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SchemaNode {
+    pub name: String,
+    pub dtype: String,
+    pub nullable: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<SchemaNode>,
+}
+
+impl SchemaNode {
+    pub fn new(name: String, dtype: &DType) -> Self {
+        Self {
+            name,
+            dtype: dtype_label(dtype),
+            nullable: dtype.is_nullable(),
+            fields: nested_dtypes(dtype)
+                .into_iter()
+                .map(|(name, dtype)| SchemaNode::new(name, &dtype))
+                .collect(),
+        }
+    }
+}
+
+/// The arrays holding a top-level column. Chunked files hold one array per chunk.
+pub fn column_chunks(root: &ArrayData, name: &str) -> VortexResult<Vec<ArrayData>> {
+    if root.encoding().id() == ChunkedEncoding::ID {
+        Ok(ChunkedArray::try_from(root.clone())?
+            .chunks()
+            .filter_map(|chunk| find_child(&chunk, name))
+            .collect())
+    } else {
+        Ok(find_child(root, name).into_iter().collect())
+    }
+}
+
+/// Summary of a top-level column, across all chunks of the file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnSummary {
+    pub dtype: String,
+    pub len: usize,
+    pub nbytes: usize,
+    /// Distinct encoding trees used by the chunks of the column.
+    pub encodings: Vec<String>,
+}
+
+impl ColumnSummary {
+    pub fn try_new(root: &ArrayData, name: &str) -> VortexResult<Option<Self>> {
+        let Some(dtype) = root.dtype().as_struct().and_then(|st| {
+            st.names()
+                .iter()
+                .zip(st.dtypes().iter())
+                .find(|(field_name, _)| field_name.as_ref() == name)
+                .map(|(_, dtype)| dtype.to_string())
+        }) else {
+            return Ok(None);
+        };
+
+        let chunks = column_chunks(root, name)?;
+        let encodings: BTreeSet<String> = chunks.iter().map(encoding_tree).collect();
+
+        Ok(Some(Self {
+            dtype,
+            len: chunks.iter().map(|chunk| chunk.len()).sum(),
+            nbytes: chunks.iter().map(node_nbytes).sum(),
+            encodings: encodings.into_iter().collect(),
+        }))
+    }
+}
+
+/// A top-level column as it appears on each side of a comparison.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnDiff {
+    pub name: String,
+    pub left: Option<ColumnSummary>,
+    pub right: Option<ColumnSummary>,
+}
+
+impl ColumnDiff {
+    pub fn is_changed(&self) -> bool {
+        self.left != self.right
+    }
+}
+
+/// Match up the top-level columns of two files by name.
+///
+/// Columns are listed in the order of the left file, followed by columns only present on the right.
+pub fn diff_columns(left: &ArrayData, right: &ArrayData) -> VortexResult<Vec<ColumnDiff>> {
+    let mut names: Vec<String> = Vec::new();
+    for array in [left, right] {
+        if let Some(st) = array.dtype().as_struct() {
+            for name in st.names().iter() {
+                if !names
+                    .iter()
+                    .any(|existing| existing.as_str() == name.as_ref())
+                {
+                    names.push(name.to_string());
+                }
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            Ok(ColumnDiff {
+                left: ColumnSummary::try_new(left, &name)?,
+                right: ColumnSummary::try_new(right, &name)?,
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Encoding and size of a node, the parts of it that reloading a file can change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeShape {
+    pub encoding: String,
+    pub nbytes: usize,
+}
+
+/// A node that differs between two versions of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeChange {
+    pub path: Vec<String>,
+    /// The node in the old version, `None` if it was added.
+    pub before: Option<NodeShape>,
+    /// The node in the new version, `None` if it was removed.
+    pub after: Option<NodeShape>,
+}
+
+/// Match up the nodes of two versions of a file by path, listing those whose encoding or size
+/// changed, along with added and removed nodes.
+pub fn diff_trees(before: &ArrayData, after: &ArrayData) -> Vec<NodeChange> {
+    fn shapes(
+        array: &ArrayData,
+        path: &mut Vec<String>,
+        shapes_by_path: &mut BTreeMap<Vec<String>, NodeShape>,
+    ) {
+        shapes_by_path.insert(
+            path.clone(),
+            NodeShape {
+                encoding: encoding_label(array),
+                nbytes: node_nbytes(array),
+            },
+        );
+        for (name, child) in node_children(array) {
+            path.push(name);
+            shapes(&child, path, shapes_by_path);
+            path.pop();
+        }
+    }
+
+    let mut before_shapes = BTreeMap::new();
+    shapes(before, &mut Vec::new(), &mut before_shapes);
+    let mut after_shapes = BTreeMap::new();
+    shapes(after, &mut Vec::new(), &mut after_shapes);
+
+    diff_shapes(&before_shapes, &after_shapes)
+}
+
+/// List the paths whose shape differs between two versions of a tree, in path order.
+fn diff_shapes(
+    before_shapes: &BTreeMap<Vec<String>, NodeShape>,
+    after_shapes: &BTreeMap<Vec<String>, NodeShape>,
+) -> Vec<NodeChange> {
+    let paths: BTreeSet<&Vec<String>> = before_shapes.keys().chain(after_shapes.keys()).collect();
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let before = before_shapes.get(path).cloned();
+            let after = after_shapes.get(path).cloned();
+            (before != after).then(|| NodeChange {
+                path: path.clone(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shapes(nodes: &[(&str, &str, usize)]) -> BTreeMap<Vec<String>, NodeShape> {
+        nodes
+            .iter()
+            .map(|(path, encoding, nbytes)| {
+                let path = path
+                    .split('/')
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                let shape = NodeShape {
+                    encoding: encoding.to_string(),
+                    nbytes: *nbytes,
+                };
+                (path, shape)
+            })
+            .collect()
+    }
+
+    fn shape(encoding: &str, nbytes: usize) -> Option<NodeShape> {
+        Some(NodeShape {
+            encoding: encoding.to_string(),
+            nbytes,
+        })
+    }
+
+    #[test]
+    fn unchanged_trees() {
+        let tree = shapes(&[("", "vortex.struct", 100), ("a", "vortex.primitive", 100)]);
+        assert!(diff_shapes(&tree, &tree).is_empty());
+    }
+
+    #[test]
+    fn changed_encoding_and_size() {
+        let before = shapes(&[
+            ("", "vortex.struct", 100),
+            ("a", "vortex.primitive", 60),
+            ("b", "vortex.varbinview", 40),
+        ]);
+        let after = shapes(&[
+            ("", "vortex.struct", 70),
+            ("a", "fastlanes.bitpacked", 30),
+            ("b", "vortex.varbinview", 40),
+        ]);
+
+        assert_eq!(
+            diff_shapes(&before, &after),
+            vec![
+                NodeChange {
+                    path: Vec::new(),
+                    before: shape("vortex.struct", 100),
+                    after: shape("vortex.struct", 70),
+                },
+                NodeChange {
+                    path: vec!["a".to_string()],
+                    before: shape("vortex.primitive", 60),
+                    after: shape("fastlanes.bitpacked", 30),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn added_and_removed_nodes() {
+        let before = shapes(&[("", "vortex.dict", 10), ("codes", "vortex.primitive", 10)]);
+        let after = shapes(&[
+            ("", "vortex.dict", 10),
+            ("codes", "fastlanes.bitpacked", 10),
+            ("codes/patches", "vortex.sparse", 2),
+        ]);
+
+        let changes = diff_shapes(&before, &after);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[1].path, vec!["codes", "patches"]);
+        assert_eq!(changes[1].before, None);
+        assert_eq!(changes[1].after, shape("vortex.sparse", 2));
+
+        let changes = diff_shapes(&after, &before);
+        assert_eq!(changes[1].before, shape("vortex.sparse", 2));
+        assert_eq!(changes[1].after, None);
+    }
+}